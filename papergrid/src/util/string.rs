@@ -7,7 +7,7 @@
 /// Returns string width and count lines of a string. It's a combination of [`string_width_multiline`] and [`count_lines`].
 #[cfg(feature = "std")]
 pub fn get_text_dimension(text: &str) -> (usize, usize) {
-    #[cfg(not(feature = "ansi"))]
+    #[cfg(not(any(feature = "ansi", feature = "grapheme")))]
     {
         let (lines, acc, max) = text.chars().fold((1, 0, 0), |(lines, acc, max), c| {
             if c == '\n' {
@@ -21,7 +21,7 @@ pub fn get_text_dimension(text: &str) -> (usize, usize) {
         (lines, acc.max(max))
     }
 
-    #[cfg(feature = "ansi")]
+    #[cfg(any(feature = "ansi", feature = "grapheme"))]
     {
         get_lines(text)
             .map(|line| get_line_width(&line))
@@ -33,7 +33,7 @@ pub fn get_text_dimension(text: &str) -> (usize, usize) {
 pub fn get_line_width(text: &str) -> usize {
     #[cfg(not(feature = "ansi"))]
     {
-        unicode_width::UnicodeWidthStr::width(text)
+        get_string_width(text)
     }
 
     #[cfg(feature = "ansi")]
@@ -44,7 +44,7 @@ pub fn get_line_width(text: &str) -> usize {
         ansitok::parse_ansi(text)
             .filter(|e| e.kind() == ansitok::ElementKind::Text)
             .map(|e| &text[e.start()..e.end()])
-            .map(unicode_width::UnicodeWidthStr::width)
+            .map(get_string_width)
             .sum()
     }
 }
@@ -53,10 +53,7 @@ pub fn get_line_width(text: &str) -> usize {
 pub fn get_text_width(text: &str) -> usize {
     #[cfg(not(feature = "ansi"))]
     {
-        text.lines()
-            .map(unicode_width::UnicodeWidthStr::width)
-            .max()
-            .unwrap_or(0)
+        text.lines().map(get_string_width).max().unwrap_or(0)
     }
 
     #[cfg(feature = "ansi")]
@@ -66,13 +63,79 @@ pub fn get_text_width(text: &str) -> usize {
 }
 
 /// Returns a char width.
+///
+/// Notice that a width of a character may depend on its neighbours (like in emoji sequences),
+/// so [`get_graphemes`] and [`get_grapheme_width`] must be preferred.
 pub fn get_char_width(c: char) -> usize {
     unicode_width::UnicodeWidthChar::width(c).unwrap_or_default()
 }
 
 /// Returns a string width (accouting all characters).
 pub fn get_string_width(text: &str) -> usize {
-    unicode_width::UnicodeWidthStr::width(text)
+    #[cfg(not(feature = "grapheme"))]
+    {
+        unicode_width::UnicodeWidthStr::width(text)
+    }
+
+    #[cfg(feature = "grapheme")]
+    {
+        get_graphemes(text).map(get_grapheme_width).sum()
+    }
+}
+
+/// Returns an iterator over user-perceived characters of a string.
+///
+/// With `grapheme` feature on these are extended grapheme clusters
+/// (so a family emoji or a letter with a combining mark is a single item),
+/// otherwise these are `char`s.
+pub fn get_graphemes(text: &str) -> impl Iterator<Item = &str> {
+    #[cfg(not(feature = "grapheme"))]
+    {
+        text.char_indices()
+            .map(move |(i, c)| &text[i..i + c.len_utf8()])
+    }
+
+    #[cfg(feature = "grapheme")]
+    {
+        unicode_segmentation::UnicodeSegmentation::graphemes(text, true)
+    }
+}
+
+/// Returns a width of a user-perceived character, given by [`get_graphemes`].
+pub fn get_grapheme_width(grapheme: &str) -> usize {
+    #[cfg(not(feature = "grapheme"))]
+    {
+        unicode_width::UnicodeWidthStr::width(grapheme)
+    }
+
+    #[cfg(feature = "grapheme")]
+    {
+        const VARIATION_SELECTOR_EMOJI: char = '\u{FE0F}';
+
+        let is_flag = grapheme
+            .chars()
+            .filter(|&c| is_regional_indicator(c))
+            .count()
+            == 2;
+        let is_emoji = grapheme.contains(VARIATION_SELECTOR_EMOJI);
+        if is_flag || is_emoji {
+            return 2;
+        }
+
+        // a wide base character (like an emoji) takes the whole cluster
+        // (like modifiers and characters joined by ZWJ) into its cell.
+        let mut chars = grapheme.chars();
+        match chars.next().map(get_char_width) {
+            Some(2) => 2,
+            Some(width) => width + chars.map(get_char_width).sum::<usize>(),
+            None => 0,
+        }
+    }
+}
+
+#[cfg(feature = "grapheme")]
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
 }
 
 /// Calculates a number of lines.
@@ -196,6 +259,21 @@ mod tests {
         assert_eq!(get_text_width("Go 👍\nC 😎"), 5);
     }
 
+    #[cfg(feature = "grapheme")]
+    #[test]
+    fn string_width_grapheme_test() {
+        // a family emoji (ZWJ sequence), a flag, a skin tone and an emoji presentation selector
+        assert_eq!(get_line_width("👨‍👩‍👧"), 2);
+        assert_eq!(get_line_width("🇺🇦"), 2);
+        assert_eq!(get_line_width("👍🏽"), 2);
+        assert_eq!(get_line_width("❤️"), 2);
+        // combining marks
+        assert_eq!(get_line_width("e\u{301}"), 1);
+        assert_eq!(get_line_width("नमस्ते"), 4);
+        assert_eq!(get_graphemes("नमस्ते").count(), 3);
+        assert_eq!(get_text_dimension("👨‍👩‍👧 ok\n🇺🇦"), (2, 5));
+    }
+
     #[cfg(feature = "ansi")]
     #[test]
     fn colored_string_width_test() {
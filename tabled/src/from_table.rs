@@ -0,0 +1,321 @@
+use std::{error::Error, fmt};
+
+use crate::Tabled;
+
+/// FromTable is a reverse of [`Tabled`],
+/// it creates values out of a rendered table.
+///
+/// The table is expected to be rendered by one of [`Style`] presets which have vertical lines,
+/// like [`Style::ascii`], [`Style::psql`], [`Style::markdown`], [`Style::modern`] or [`Style::rounded`].
+/// Columns are matched by [`Tabled::headers`], so their order and extra columns don't matter.
+///
+/// Cells which span a few lines are not supported, each line is considered as a row.
+///
+/// ```
+/// use tabled::{FromTable, Table, Tabled};
+///
+/// #[derive(Tabled, FromTable, Debug, PartialEq)]
+/// struct Language {
+///     name: String,
+///     year: u16,
+/// }
+///
+/// let languages = vec![
+///     Language { name: String::from("C"), year: 1972 },
+///     Language { name: String::from("Rust"), year: 2010 },
+/// ];
+///
+/// let table = Table::new(&languages).to_string();
+///
+/// assert_eq!(Language::from_table(&table), Ok(languages));
+/// ```
+///
+/// [`Style`]: crate::settings::Style
+/// [`Style::ascii`]: crate::settings::Style::ascii
+/// [`Style::psql`]: crate::settings::Style::psql
+/// [`Style::markdown`]: crate::settings::Style::markdown
+/// [`Style::modern`]: crate::settings::Style::modern
+/// [`Style::rounded`]: crate::settings::Style::rounded
+pub trait FromTable: Tabled + Sized {
+    /// Creates a value out of cells,
+    /// which are given in the same order as [`Tabled::headers`].
+    fn from_fields(fields: &[&str]) -> Result<Self, FromTableError>;
+
+    /// Parses a rendered table into a list of values.
+    fn from_table(text: &str) -> Result<Vec<Self>, FromTableError> {
+        let mut lines = parse_lines(text);
+
+        let headers = Self::headers();
+        let position = lines.iter().position(|cells| {
+            headers
+                .iter()
+                .all(|header| cells.iter().any(|cell| cell == header))
+        });
+
+        let position = match position {
+            Some(position) => position,
+            None => {
+                let header = headers.into_iter().next().unwrap_or_default();
+                return Err(FromTableError::MissingColumn(header.into_owned()));
+            }
+        };
+
+        let rows = lines.split_off(position + 1);
+        let header = lines.pop().unwrap_or_default();
+        let columns = match_columns(&header, &headers)?;
+
+        let mut list = Vec::with_capacity(rows.len());
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != header.len() {
+                return Err(FromTableError::InvalidRow(i));
+            }
+
+            let fields = columns
+                .iter()
+                .map(|&col| row[col].as_str())
+                .collect::<Vec<_>>();
+
+            let value = Self::from_fields(&fields).map_err(|err| err.set_row(i))?;
+            list.push(value);
+        }
+
+        Ok(list)
+    }
+}
+
+/// An error which may occur while parsing a table by [`FromTable`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromTableError {
+    /// A column with a given name is not found.
+    MissingColumn(String),
+    /// A row with a given index has a different amount of cells than the header.
+    InvalidRow(usize),
+    /// A cell can't be parsed into a field.
+    InvalidValue {
+        /// An index of a row (without a header).
+        row: usize,
+        /// An index of a column in [`Tabled::headers`].
+        column: usize,
+        /// A text of a cell.
+        text: String,
+    },
+}
+
+impl FromTableError {
+    /// Creates an [`FromTableError::InvalidValue`] error,
+    /// a row is set by [`FromTable::from_table`].
+    pub fn invalid_value(column: usize, text: &str) -> Self {
+        Self::InvalidValue {
+            row: 0,
+            column,
+            text: text.to_owned(),
+        }
+    }
+
+    /// Shifts a column of [`FromTableError::InvalidValue`],
+    /// it's used to report an error of an inlined value.
+    pub fn shift_column(self, offset: usize) -> Self {
+        match self {
+            Self::InvalidValue { row, column, text } => Self::InvalidValue {
+                row,
+                column: column + offset,
+                text,
+            },
+            err => err,
+        }
+    }
+
+    fn set_row(self, row: usize) -> Self {
+        match self {
+            Self::InvalidValue { column, text, .. } => Self::InvalidValue { row, column, text },
+            err => err,
+        }
+    }
+}
+
+impl fmt::Display for FromTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingColumn(name) => write!(f, "column {:?} is not found", name),
+            Self::InvalidRow(row) => write!(f, "row {} has a wrong number of cells", row),
+            Self::InvalidValue { row, column, text } => write!(
+                f,
+                "failed to parse {:?} at row {} column {}",
+                text, row, column
+            ),
+        }
+    }
+}
+
+impl Error for FromTableError {}
+
+macro_rules! from_str_table {
+    ( $($t:ty)+ ) => {
+        $(
+            impl FromTable for $t {
+                fn from_fields(fields: &[&str]) -> Result<Self, FromTableError> {
+                    let text = fields.first().copied().unwrap_or_default();
+                    text.parse().map_err(|_| FromTableError::invalid_value(0, text))
+                }
+            }
+        )+
+    };
+}
+
+from_str_table! { String char bool }
+from_str_table! { isize usize u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 f32 f64 }
+
+macro_rules! tuple_from_table {
+    ( $($name:ident)+ ) => {
+        impl<$($name: FromTable),+> FromTable for ($($name,)+){
+            fn from_fields(fields: &[&str]) -> Result<Self, FromTableError> {
+                let mut offset = 0;
+                let value = ($({
+                    let end = offset + $name::LENGTH;
+                    let cells = fields.get(offset..end).unwrap_or_default();
+                    let value = $name::from_fields(cells).map_err(|err| err.shift_column(offset))?;
+                    offset = end;
+                    value
+                },)+);
+                let _ = offset;
+
+                Ok(value)
+            }
+        }
+    };
+}
+
+tuple_from_table! { A }
+tuple_from_table! { A B }
+tuple_from_table! { A B C }
+tuple_from_table! { A B C D }
+tuple_from_table! { A B C D E }
+tuple_from_table! { A B C D E F }
+
+/// Characters which are used as vertical lines, in an order of preference.
+const VERTICALS: [char; 5] = ['│', '┃', '║', '|', ':'];
+
+/// Splits a table into cells, dropping horizontal lines.
+fn parse_lines(text: &str) -> Vec<Vec<String>> {
+    let vertical = VERTICALS
+        .iter()
+        .copied()
+        .find(|&c| text.contains(c))
+        .unwrap_or('|');
+
+    let lines = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter(|line| !is_horizontal_line(line, vertical))
+        .collect::<Vec<_>>();
+
+    // a frame is checked for all lines at once as an empty first or last cell looks like it
+    let has_frame = lines.iter().all(|line| {
+        let line = line.trim();
+        line.starts_with(vertical) && line.ends_with(vertical)
+    });
+
+    lines
+        .into_iter()
+        .map(|line| split_line(line, vertical, has_frame))
+        .collect()
+}
+
+fn split_line(line: &str, vertical: char, has_frame: bool) -> Vec<String> {
+    let mut line = line;
+    if has_frame {
+        line = line.trim();
+        line = line.strip_prefix(vertical).unwrap_or(line);
+        line = line.strip_suffix(vertical).unwrap_or(line);
+    }
+
+    line.split(vertical)
+        .map(|cell| cell.trim().to_owned())
+        .collect()
+}
+
+/// Checks whether a line consists of border characters only.
+///
+/// A cell with a border character (like `-`) is distinguished from a line
+/// as a line is at least 3 characters long because of padding.
+fn is_horizontal_line(line: &str, vertical: char) -> bool {
+    if !line.chars().all(|c| c.is_whitespace() || is_border(c)) {
+        return false;
+    }
+
+    if !line.contains(vertical) {
+        return true;
+    }
+
+    let segments = line
+        .trim()
+        .split(vertical)
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+
+    !segments.is_empty()
+        && segments
+            .iter()
+            .all(|segment| segment.chars().count() >= 3 && !segment.contains(char::is_whitespace))
+}
+
+fn is_border(c: char) -> bool {
+    matches!(
+        c,
+        '-' | '=' | '+' | '.' | ':' | '\'' | '~' | '*' | '#' | '^' | '`' | '_' | '|'
+    ) || ('\u{2500}'..='\u{257F}').contains(&c)
+}
+
+/// Returns an index of a header cell for each header.
+///
+/// Each header cell is matched only once, so duplicate headers take consecutive cells.
+fn match_columns<S>(header: &[String], headers: &[S]) -> Result<Vec<usize>, FromTableError>
+where
+    S: AsRef<str>,
+{
+    let mut used = vec![false; header.len()];
+    headers
+        .iter()
+        .map(|name| {
+            let name = name.as_ref();
+            let col = (0..header.len())
+                .find(|&col| !used[col] && header[col] == name)
+                .ok_or_else(|| FromTableError::MissingColumn(name.to_owned()))?;
+            used[col] = true;
+            Ok(col)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lines_test() {
+        let table = "+---+----+\n| a | bc |\n+---+----+\n| - | 1  |\n+---+----+";
+        assert_eq!(parse_lines(table), [["a", "bc"], ["-", "1"]]);
+
+        let table = " a | bc \n---+----\n   | 1  \n   |    ";
+        assert_eq!(parse_lines(table), [["a", "bc"], ["", "1"], ["", ""]]);
+
+        let table = "| a | bc |\n|---|----|\n| - | 1  |";
+        assert_eq!(parse_lines(table), [["a", "bc"], ["-", "1"]]);
+
+        let table = "┌───┬────┐\n│ a │ bc │\n├───┼────┤\n│ - │ 1  │\n└───┴────┘";
+        assert_eq!(parse_lines(table), [["a", "bc"], ["-", "1"]]);
+    }
+
+    #[test]
+    fn match_columns_test() {
+        let header = ["b", "a", "b"].map(String::from);
+
+        assert_eq!(match_columns(&header, &["a", "b"]), Ok(vec![1, 0]));
+        assert_eq!(match_columns(&header, &["b", "a", "b"]), Ok(vec![0, 1, 2]));
+        assert_eq!(
+            match_columns(&header, &["a", "a"]),
+            Err(FromTableError::MissingColumn(String::from("a")))
+        );
+    }
+}
@@ -10,6 +10,8 @@ use crate::grid::{
 pub struct ColoredConfig {
     config: SpannedConfig,
     colors: ColorMap,
+    directions: Option<EntityMap<Direction>>,
+    decimal_points: Option<EntityMap<Option<char>>>,
 }
 
 impl ColoredConfig {
@@ -18,6 +20,8 @@ impl ColoredConfig {
         Self {
             config,
             colors: ColorMap::default(),
+            directions: None,
+            decimal_points: None,
         }
     }
 
@@ -60,6 +64,58 @@ impl ColoredConfig {
         &self.colors
     }
 
+    /// Set a text direction for a given cell.
+    ///
+    /// Cells which were not set are considered to be [`Direction::Ltr`],
+    /// while in case no direction is set at all a text is left in its logical order.
+    pub fn set_direction(&mut self, pos: Entity, direction: Direction) -> &mut Self {
+        match self.directions.as_mut() {
+            Some(map) => map.insert(pos, direction),
+            None => {
+                let mut directions = EntityMap::new(Direction::Ltr);
+                directions.insert(pos, direction);
+                self.directions = Some(directions);
+            }
+        }
+
+        self
+    }
+
+    /// Returns a text direction of a given entity,
+    /// or `None` in case no direction was set.
+    pub fn get_direction(&self, entity: Entity) -> Option<Direction> {
+        self.directions.as_ref().map(|map| *map.get(entity))
+    }
+
+    /// Set a decimal point by which numbers of a given cell are aligned.
+    ///
+    /// Lines of such cells are padded when a table is rendered,
+    /// so the points line up across a column.
+    pub fn set_decimal_point(&mut self, pos: Entity, point: Option<char>) -> &mut Self {
+        match self.decimal_points.as_mut() {
+            Some(map) => map.insert(pos, point),
+            None => {
+                let mut points = EntityMap::new(None);
+                points.insert(pos, point);
+                self.decimal_points = Some(points);
+            }
+        }
+
+        self
+    }
+
+    /// Returns a decimal point by which numbers of a given entity are aligned.
+    pub fn get_decimal_point(&self, entity: Entity) -> Option<char> {
+        self.decimal_points
+            .as_ref()
+            .and_then(|map| *map.get(entity))
+    }
+
+    /// Checks whether any decimal point was set.
+    pub fn has_decimal_points(&self) -> bool {
+        self.decimal_points.is_some()
+    }
+
     /// Returns an inner config.
     pub fn into_inner(self) -> SpannedConfig {
         self.config
@@ -117,3 +173,64 @@ impl crate::grid::colors::Colors for ColorMap {
             .unwrap_or(true)
     }
 }
+
+/// Direction sets a direction of a text in a cell or in a whole table,
+/// so right-to-left scripts like Hebrew or Arabic are rendered correctly.
+///
+/// Once it's set, a text is reordered from a logical into a visual order line by line
+/// (according to a simplified Unicode Bidirectional Algorithm),
+/// so it's displayed properly by terminals which don't do it on their own.
+/// As lines are reordered when a table is rendered,
+/// wrapping (see [`Width::wrap`]) is done in a logical order and so it respects bidirectional runs.
+///
+/// In right-to-left cells [`AlignmentHorizontal::Left`] means a start of a line,
+/// which is its right side, and [`AlignmentHorizontal::Right`] means an end of a line.
+///
+/// Columns are not mirrored by the setting, use [`Reverse::columns`] for it.
+///
+/// Lines which contain ANSI sequences are not reordered.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{builder::Builder, settings::{Direction, Reverse, Style}};
+///
+/// let mut builder = Builder::default();
+/// builder.push_record(["שם", "גיל"]);
+/// builder.push_record(["דוד", "7"]);
+/// builder.push_record(["רות (Ruth)", "42"]);
+///
+/// let mut table = builder.build();
+/// table
+///     .with(Style::modern())
+///     .with(Direction::Rtl)
+///     .with(Reverse::columns(0, 0));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "┌─────┬────────────┐\n",
+///         "│ ליג │         םש │\n",
+///         "├─────┼────────────┤\n",
+///         "│   7 │        דוד │\n",
+///         "├─────┼────────────┤\n",
+///         "│  42 │ (Ruth) תור │\n",
+///         "└─────┴────────────┘",
+///     ),
+/// );
+/// ```
+///
+/// [`Width::wrap`]: crate::settings::Width::wrap
+/// [`AlignmentHorizontal::Left`]: crate::grid::config::AlignmentHorizontal::Left
+/// [`AlignmentHorizontal::Right`]: crate::grid::config::AlignmentHorizontal::Right
+/// [`Reverse::columns`]: crate::settings::Reverse::columns
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction {
+    /// A left-to-right text.
+    Ltr,
+    /// A right-to-left text.
+    Rtl,
+    /// A direction is detected by a first strong character of a cell,
+    /// falling back to a left-to-right one.
+    Auto,
+}
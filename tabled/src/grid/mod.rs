@@ -30,7 +30,7 @@ pub mod config {
 
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-    pub use super::colored_config::{ColorMap, ColoredConfig};
+    pub use super::colored_config::{ColorMap, ColoredConfig, Direction};
 
     pub use super::compact_multiline_config::CompactMultilineConfig;
 }
@@ -0,0 +1,91 @@
+use std::{
+    borrow::{Borrow, Cow},
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+/// HeaderProvider gives column names by keys at runtime,
+/// for example from a translation catalog.
+///
+/// It's used by [`Table::new_with_headers`] together with [`Tabled::header_keys`],
+/// which `#[derive(Tabled)]` implements by `#[tabled(header_key = "user.name")]` attributes.
+///
+/// It's implemented for [`HashMap`], [`BTreeMap`] and closures.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+/// use tabled::{settings::Style, HeaderProvider, Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct User {
+///     #[tabled(header_key = "user.name")]
+///     name: &'static str,
+///     #[tabled(header_key = "user.age")]
+///     age: u8,
+/// }
+///
+/// struct German;
+///
+/// impl HeaderProvider for German {
+///     fn header(&self, key: &str) -> Option<Cow<'_, str>> {
+///         match key {
+///             "user.name" => Some(Cow::Borrowed("Name")),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let users = [User { name: "Anna", age: 31 }];
+///
+/// let mut table = Table::new_with_headers(users, &German);
+/// table.with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " Name | age \n",
+///         "------+-----\n",
+///         " Anna | 31  ",
+///     ),
+/// );
+/// ```
+///
+/// [`Table::new_with_headers`]: crate::Table::new_with_headers
+/// [`Tabled::header_keys`]: crate::Tabled::header_keys
+pub trait HeaderProvider {
+    /// Returns a column name by a key,
+    /// or [`None`] in case the key is unknown.
+    fn header(&self, key: &str) -> Option<Cow<'_, str>>;
+}
+
+impl<K, V, S> HeaderProvider for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: AsRef<str>,
+    S: BuildHasher,
+{
+    fn header(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.get(key).map(|text| Cow::Borrowed(text.as_ref()))
+    }
+}
+
+impl<K, V> HeaderProvider for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+    V: AsRef<str>,
+{
+    fn header(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.get(key).map(|text| Cow::Borrowed(text.as_ref()))
+    }
+}
+
+impl<F> HeaderProvider for F
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn header(&self, key: &str) -> Option<Cow<'_, str>> {
+        (self)(key).map(Cow::Owned)
+    }
+}
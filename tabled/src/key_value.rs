@@ -0,0 +1,80 @@
+use std::{borrow::Cow, fmt::Display};
+
+use crate::Tabled;
+
+/// KeyValue is a pair of [`Display`] values,
+/// which is rendered as `Key` and `Value` columns.
+///
+/// Unlike a tuple `(K, V)` it doesn't require [`Tabled`] for its parts,
+/// so any entries of a map can be rendered.
+/// It's used by [`Table::from_map`].
+///
+/// # Example
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use tabled::{KeyValue, Table};
+///
+/// let hosts = [("localhost", Ipv4Addr::LOCALHOST)];
+/// let table = Table::new(hosts.iter().map(|(host, ip)| KeyValue::new(host, ip)));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+-----------+-----------+\n",
+///         "| Key       | Value     |\n",
+///         "+-----------+-----------+\n",
+///         "| localhost | 127.0.0.1 |\n",
+///         "+-----------+-----------+",
+///     ),
+/// );
+/// ```
+///
+/// [`Table::from_map`]: crate::Table::from_map
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyValue<K, V> {
+    key: K,
+    value: V,
+}
+
+impl<K, V> KeyValue<K, V> {
+    /// Creates a new [`KeyValue`] pair.
+    pub const fn new(key: K, value: V) -> Self {
+        Self { key, value }
+    }
+
+    /// Returns a key.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns a value.
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<K, V> From<(K, V)> for KeyValue<K, V> {
+    fn from((key, value): (K, V)) -> Self {
+        Self::new(key, value)
+    }
+}
+
+impl<K, V> Tabled for KeyValue<K, V>
+where
+    K: Display,
+    V: Display,
+{
+    const LENGTH: usize = 2;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![
+            Cow::Owned(self.key.to_string()),
+            Cow::Owned(self.value.to_string()),
+        ]
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("Key"), Cow::Borrowed("Value")]
+    }
+}
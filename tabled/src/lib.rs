@@ -179,6 +179,7 @@
 //! - `derive`  - Used by default. A support for `Tabled` derive macro.
 //! - `ansi`    - A support for ANSI sequences.
 //! - `macros`  - A support for `row!`, `col!` macro.
+//! - `grapheme` - A width calculation by grapheme clusters (like emoji sequences and combining marks).
 //!
 //! # Advanced
 //!
@@ -214,6 +215,9 @@
 //! );
 //! ```
 //!
+//! It can be also written row by row by [`IterTable::stream`],
+//! which is handy for long running producers like logs.
+//!
 //! ### [`CompactTable`]
 //!
 //! Alloc free can be configured ('1) to not make any allocations.
@@ -257,6 +261,7 @@
 //!
 //! [`Builder`]: crate::builder::Builder
 //! [`IterTable`]: crate::tables::IterTable
+//! [`IterTable::stream`]: crate::tables::IterTable::stream
 //! [`CompactTable`]: crate::tables::CompactTable
 //! [`fmt::Write`]: core::fmt::Write
 //! [`row!`]: crate::row
@@ -290,8 +295,18 @@
 
 mod util;
 
+#[cfg(feature = "std")]
+mod from_table;
+#[cfg(feature = "std")]
+mod header_provider;
+#[cfg(feature = "std")]
+mod key_value;
 #[cfg(feature = "std")]
 mod tabled;
+#[cfg(feature = "std")]
+mod tabled_presentation;
+#[cfg(feature = "std")]
+mod tabled_values;
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -309,6 +324,26 @@ pub mod grid;
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use crate::{tabled::Tabled, tables::Table};
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::tabled_values::{TabledValue, TabledValues, ToTabledValue};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::tabled_presentation::TabledPresentation;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::from_table::{FromTable, FromTableError};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::header_provider::HeaderProvider;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::key_value::KeyValue;
+
 /// A derive macro to implement a [`Tabled`] trait.
 ///
 /// The macro available only when `derive` feature in turned on (and it is by default).
@@ -362,6 +397,47 @@ pub use crate::{tabled::Tabled, tables::Table};
 /// }
 /// ```
 ///
+/// A column can be also hidden depending on data.
+///
+/// - `#[tabled(skip_if_all = "is_empty")]` removes a column from a [`Table`] built by [`Table::new`]
+///   in case a given predicate returns `true` for each row (only for structs).
+/// - `#[tabled(display_if = "is_shown")]` shows an empty cell in case a given predicate returns `false`.
+///
+/// ```
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Task {
+///     name: &'static str,
+///     #[tabled(skip_if_all = "str::is_empty")]
+///     note: &'static str,
+///     #[tabled(display_if = "is_late")]
+///     delay: u32,
+/// }
+///
+/// fn is_late(delay: &u32) -> bool {
+///     *delay > 0
+/// }
+///
+/// let tasks = [
+///     Task { name: "build", note: "", delay: 0 },
+///     Task { name: "test", note: "", delay: 3 },
+/// ];
+///
+/// assert_eq!(
+///     Table::new(tasks).to_string(),
+///     concat!(
+///         "+-------+-------+\n",
+///         "| name  | delay |\n",
+///         "+-------+-------+\n",
+///         "| build |       |\n",
+///         "+-------+-------+\n",
+///         "| test  | 3     |\n",
+///         "+-------+-------+",
+///     ),
+/// );
+/// ```
+///
 /// ### Set column order
 ///
 /// You can change the order in which they will be displayed in table.
@@ -445,6 +521,33 @@ pub use crate::{tabled::Tabled, tables::Table};
 /// }
 /// ```
 ///
+/// Options and collections have their own attributes, so there's no need in a function for them.
+///
+/// - `#[tabled(display_option = "-")]` shows a value of `Some` and a given text for `None`.
+/// - `#[tabled(join = ", ")]` joins items of a collection by a given separator.
+/// - `#[tabled(nested_table)]` renders a collection of [`Tabled`] items as an inner [`Table`].
+///
+/// ```
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Package {
+///     name: &'static str,
+///     #[tabled(display_option = "-")]
+///     license: Option<&'static str>,
+///     #[tabled(join = ", ")]
+///     authors: Vec<&'static str>,
+/// }
+///
+/// let package = Package {
+///     name: "tabled",
+///     license: None,
+///     authors: vec!["Maxim", "Zhiburt"],
+/// };
+///
+/// assert_eq!(package.fields(), ["tabled", "-", "Maxim, Zhiburt"]);
+/// ```
+///
 /// ### Format headers
 ///
 /// Beside `#[tabled(rename = "")]` you can change a format of a column name using
@@ -464,6 +567,29 @@ pub use crate::{tabled::Tabled, tables::Table};
 /// }
 /// ```
 ///
+/// A column name can be also resolved at runtime, for example to localize it.
+/// `#[tabled(header_key = "user.name")]` sets a key which [`Table::new_with_headers`]
+/// looks up in a [`HeaderProvider`], falling back to a name from [`Tabled::headers`].
+/// It can't be set on an inlined field, as it would be shared by all its columns.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Person {
+///     #[tabled(header_key = "person.name")]
+///     name: &'static str,
+/// }
+///
+/// let mut spanish = HashMap::new();
+/// spanish.insert("person.name", "Nombre");
+///
+/// let table = Table::new_with_headers([Person { name: "Ana" }], &spanish);
+///
+/// assert!(table.to_string().contains("Nombre"));
+/// ```
+///
 /// ### Inline
 ///
 /// It's possible to inline internal data if it implements the `Tabled` trait using `#[tabled(inline)]`.
@@ -512,6 +638,258 @@ pub use crate::{tabled::Tabled, tables::Table};
 ///     price: f32,
 /// }
 /// ```
+///
+/// ### Enum mode
+///
+/// By default each variant of an enum gets its own column marked by `+`.
+/// With `#[tabled(enum_mode = "tagged")]` there's a single `kind` column with a variant name
+/// and a union of columns of all variants, which are filled only for the active variant.
+/// Fields with the same header share a column.
+///
+/// ```
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// #[tabled(enum_mode = "tagged")]
+/// enum Event {
+///     Login { user: &'static str },
+///     Transfer { user: &'static str, amount: u32 },
+///     Logout,
+/// }
+///
+/// assert_eq!(Event::headers(), ["kind", "user", "amount"]);
+/// assert_eq!(Event::Login { user: "ann" }.fields(), ["Login", "ann", ""]);
+/// ```
+///
+/// ### Column settings
+///
+/// You can set an alignment, a maximum width and a color of a column
+/// using `#[tabled(align = "right")]`, `#[tabled(max_width = 20)]` and `#[tabled(color = "red")]`.
+/// They are applied by [`Table::new`] and follow a field wherever it's placed.
+///
+/// An alignment is one of `left`, `center` and `right`.
+/// A color is a name like `red`, `bright_red` or `bg_red`, or `bold`.
+///
+/// ```
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Crate {
+///     #[tabled(max_width = 6)]
+///     name: &'static str,
+///     #[tabled(align = "right")]
+///     downloads: usize,
+/// }
+///
+/// let table = Table::new([Crate { name: "tabled_derive", downloads: 7 }]).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+--------+-----------+\n\
+///      | name   | downloads |\n\
+///      +--------+-----------+\n\
+///      | tabled |         7 |\n\
+///      +--------+-----------+"
+/// );
+/// ```
+///
+/// ### Column groups
+///
+/// Columns can be grouped by `#[tabled(group = "...")]`,
+/// in which case [`Table::new`] renders a two-row header with a group name spanning its columns.
+/// It's handy for an inlined struct.
+///
+/// ```
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Address {
+///     city: &'static str,
+///     zip: u32,
+/// }
+///
+/// #[derive(Tabled)]
+/// struct User {
+///     name: &'static str,
+///     #[tabled(inline, group = "Address")]
+///     address: Address,
+/// }
+///
+/// let users = [User { name: "Ann", address: Address { city: "Lviv", zip: 79000 } }];
+///
+/// assert_eq!(
+///     Table::new(users).to_string(),
+///     concat!(
+///         "+------+------+-------+\n",
+///         "| name | Address      |\n",
+///         "+      +------+-------+\n",
+///         "|      | city | zip   |\n",
+///         "+------+------+-------+\n",
+///         "| Ann  | Lviv | 79000 |\n",
+///         "+------+------+-------+",
+///     ),
+/// );
+/// ```
+///
+/// ### Presentation
+///
+/// A style, a header and a footer of a whole table can be declared on a type,
+/// they are applied by [`Table::new`] (see [`TabledPresentation`]).
+///
+/// - `#[tabled(style = "rounded")]` sets one of [`Style`] constructors by its name.
+/// - `#[tabled(header = "Users")]` adds a row above column names.
+/// - `#[tabled(footer_fn = "Self::totals")]` adds a row below all rows,
+///   the function gets a list of rows (`&[&Self]`) and returns its text.
+///
+/// ```
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// #[tabled(style = "psql", footer_fn = "Self::totals")]
+/// struct Sale {
+///     item: &'static str,
+///     price: u32,
+/// }
+///
+/// impl Sale {
+///     fn totals(rows: &[&Self]) -> String {
+///         format!("total {}", rows.iter().map(|row| row.price).sum::<u32>())
+///     }
+/// }
+///
+/// let sales = [Sale { item: "tea", price: 3 }, Sale { item: "cake", price: 5 }];
+///
+/// assert_eq!(
+///     Table::new(sales).to_string(),
+///     concat!(
+///         " item | price \n",
+///         "------+-------\n",
+///         " tea  | 3     \n",
+///         " cake | 5     \n",
+///         " total 8      ",
+///     ),
+/// );
+/// ```
+///
+/// [`Style`]: crate::settings::Style
+///
+/// ### Generics
+///
+/// A generic type gets a bound for each field which uses a type parameter:
+/// `Display` for a plain field and `Tabled` for an inlined one.
+/// Fields with `display_with` or `format` get no bound.
+///
+/// The bounds can be overridden by `#[tabled(bound = "...")]` on a type or on a field.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// trait Named {
+///     fn name(&self) -> String;
+/// }
+///
+/// #[derive(Tabled)]
+/// #[tabled(bound = "T: Named")]
+/// struct Row<'a, T> {
+///     id: &'a str,
+///     #[tabled(display_with = "Named::name")]
+///     value: T,
+/// }
+/// ```
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use tabled_derive::Tabled;
+
+/// A derive macro to implement a [`TabledValues`] trait.
+///
+/// It's supposed to be used together with `#[derive(Tabled)]`
+/// and it respects the same `#[tabled(...)]` attributes.
+///
+/// Each field must implement [`ToTabledValue`],
+/// unless it uses `display_with` or `format` in which case it becomes a [`TabledValue::String`].
+/// An `inline` field must implement [`TabledValues`].
+/// A field with `#[tabled(datetime)]` becomes a [`TabledValue::DateTime`] of its `Display` output.
+///
+/// Enum variants which are not inlined are represented as strings, same as by [`Tabled`].
+/// Cells of not matched variants are [`TabledValue::Null`].
+///
+/// ```
+/// use tabled::{Tabled, TabledValue, TabledValues};
+///
+/// #[derive(Tabled, TabledValues)]
+/// struct Release {
+///     version: &'static str,
+///     downloads: u64,
+///     yanked: bool,
+///     #[tabled(datetime)]
+///     date: &'static str,
+///     #[tabled(display_with = "display_license")]
+///     license: Option<&'static str>,
+/// }
+///
+/// fn display_license(license: &Option<&str>) -> String {
+///     license.unwrap_or("unknown").to_string()
+/// }
+///
+/// let release = Release {
+///     version: "0.16.0",
+///     downloads: 1024,
+///     yanked: false,
+///     date: "2024-07-23",
+///     license: None,
+/// };
+///
+/// assert_eq!(
+///     release.values(),
+///     vec![
+///         TabledValue::String("0.16.0".into()),
+///         TabledValue::Int(1024),
+///         TabledValue::Bool(false),
+///         TabledValue::DateTime("2024-07-23".into()),
+///         TabledValue::String("unknown".into()),
+///     ],
+/// );
+/// ```
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use tabled_derive::TabledValues;
+
+/// A derive macro to implement a [`FromTable`] trait.
+///
+/// It's supposed to be used together with `#[derive(Tabled)]`
+/// and it respects the same `#[tabled(...)]` attributes.
+///
+/// Each field is parsed by [`FromStr`], even the one which uses `display_with` or `format`.
+/// An `inline` field must implement [`FromTable`].
+/// A `skip` field is set to [`Default::default`].
+/// A field with `display_option` is [`None`] in case its cell equals to the given text,
+/// and a field with `join` is split by the given separator and collected.
+///
+/// It can be derived only for a struct.
+///
+/// ```
+/// use tabled::{settings::Style, FromTable, Table, Tabled};
+///
+/// #[derive(Tabled, FromTable, Debug, PartialEq)]
+/// struct Package {
+///     name: String,
+///     #[tabled(display_option = "-")]
+///     license: Option<String>,
+///     #[tabled(join = ", ")]
+///     tags: Vec<String>,
+/// }
+///
+/// let packages = vec![
+///     Package { name: "tabled".into(), license: Some("MIT".into()), tags: vec!["table".into(), "cli".into()] },
+///     Package { name: "papergrid".into(), license: None, tags: vec![] },
+/// ];
+///
+/// let table = Table::new(&packages).with(Style::markdown()).to_string();
+///
+/// assert_eq!(Package::from_table(&table), Ok(packages));
+/// ```
+///
+/// [`FromStr`]: std::str::FromStr
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use tabled_derive::FromTable;
@@ -0,0 +1,603 @@
+//! This module contains an [`Aggregate`] setting which adds summary rows to a [`Table`].
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "derive", doc = "```")]
+#![cfg_attr(not(feature = "derive"), doc = "```ignore")]
+//! use tabled::{
+//!     settings::{
+//!         aggregate::Aggregate,
+//!         object::Columns,
+//!         Alignment, Style,
+//!     },
+//!     Table, Tabled,
+//! };
+//!
+//! #[derive(Tabled)]
+//! struct Employee {
+//!     department: &'static str,
+//!     name: &'static str,
+//!     salary: usize,
+//! }
+//!
+//! let data = [
+//!     Employee { department: "Dev", name: "Ann", salary: 300 },
+//!     Employee { department: "Ops", name: "Bob", salary: 200 },
+//!     Employee { department: "Dev", name: "Tom", salary: 250 },
+//! ];
+//!
+//! let aggregate = Aggregate::group_by(Columns::first()).sum(2);
+//! let summary = aggregate.summary_rows();
+//!
+//! let mut table = Table::new(data);
+//! table.with(Style::psql()).with(aggregate);
+//! table.modify(summary.subtotals(), Alignment::right());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " department | name     | salary \n",
+//!         "------------+----------+--------\n",
+//!         " Dev        | Ann      | 300    \n",
+//!         " Dev        | Tom      | 250    \n",
+//!         "        Dev | Subtotal |    550 \n",
+//!         " Ops        | Bob      | 200    \n",
+//!         "        Ops | Subtotal |    200 \n",
+//!         " Total      |          | 750    ",
+//!     ),
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::{cell::RefCell, ops::Range, rc::Rc};
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut, Resizable},
+    },
+    settings::{
+        location::Location,
+        object::{Columns, Object},
+        sort::{find_pinned_rows, move_rows_config, parse_number, reorder_rows, strip},
+        TableOption,
+    },
+};
+
+const DEFAULT_SUBTOTAL_LABEL: &str = "Subtotal";
+const DEFAULT_TOTAL_LABEL: &str = "Total";
+
+/// A minimum default number of digits after a decimal point of a mean,
+/// so a mean of integers isn't rounded to an integer.
+const MEAN_PRECISION: usize = 2;
+
+/// Aggregate groups rows of a [`Table`] and adds summary rows with aggregated values.
+///
+/// Rows with the same values in grouping columns are put together (in order of first appearance),
+/// and a subtotal row is inserted after each group.
+/// A grand total row is appended after the last data row.
+///
+/// The first row is considered to be a header and is not aggregated,
+/// see [`Aggregate::header`] to change the number of such rows.
+/// Panels (rows which are spanned over all columns, like [`Panel::header`]) are not aggregated either,
+/// rows in between them are grouped separately.
+///
+/// Cells are parsed as numbers, the ones which can't be parsed are ignored.
+/// Aggregated values are printed with the same number of digits after a decimal point
+/// as the most precise of the aggregated cells, and a mean is printed with at least 2 of them,
+/// see [`Aggregate::precision`] to change it.
+///
+/// A subtotal row contains values of grouping columns,
+/// a label in the first column which is neither grouped nor aggregated,
+/// and the aggregated values.
+/// A total row contains a label in the first column which is not aggregated,
+/// and the aggregated values.
+///
+/// Summary rows can be addressed afterwards by [`SummaryRows`], see [`Aggregate::summary_rows`].
+///
+/// [`Table`]: crate::Table
+/// [`Panel::header`]: crate::settings::Panel::header
+#[derive(Debug, Clone)]
+pub struct Aggregate<L> {
+    group_by: L,
+    functions: Vec<(usize, Function)>,
+    subtotal: Option<String>,
+    total: Option<String>,
+    precision: Option<usize>,
+    header: usize,
+    summary: SummaryRows,
+}
+
+impl<L> Aggregate<L> {
+    /// Group rows by columns found by a given locator,
+    /// adding a subtotal row after each group and a total row.
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// [`Column`]: crate::settings::object::Column
+    /// [`FirstColumn`]: crate::settings::object::FirstColumn
+    /// [`LastColumn`]: crate::settings::object::LastColumn
+    /// [`ByColumnName`]: crate::settings::location::ByColumnName
+    pub fn group_by(locator: L) -> Self {
+        Self {
+            group_by: locator,
+            functions: Vec::new(),
+            subtotal: Some(DEFAULT_SUBTOTAL_LABEL.to_owned()),
+            total: Some(DEFAULT_TOTAL_LABEL.to_owned()),
+            precision: None,
+            header: 1,
+            summary: SummaryRows::default(),
+        }
+    }
+
+    /// Sum values of a column.
+    pub fn sum(self, column: usize) -> Self {
+        self.function(column, Function::Sum)
+    }
+
+    /// Count not empty cells of a column.
+    pub fn count(self, column: usize) -> Self {
+        self.function(column, Function::Count)
+    }
+
+    /// Find a minimum value of a column.
+    pub fn min(self, column: usize) -> Self {
+        self.function(column, Function::Min)
+    }
+
+    /// Find a maximum value of a column.
+    pub fn max(self, column: usize) -> Self {
+        self.function(column, Function::Max)
+    }
+
+    /// Calculate an average value of a column.
+    pub fn mean(self, column: usize) -> Self {
+        self.function(column, Function::Mean)
+    }
+
+    /// Set a label of subtotal rows.
+    ///
+    /// `None` disables subtotal rows.
+    pub fn subtotal_label<S>(mut self, label: Option<S>) -> Self
+    where
+        S: Into<String>,
+    {
+        self.subtotal = label.map(Into::into);
+        self
+    }
+
+    /// Set a label of a total row.
+    ///
+    /// `None` disables a total row.
+    pub fn total_label<S>(mut self, label: Option<S>) -> Self
+    where
+        S: Into<String>,
+    {
+        self.total = label.map(Into::into);
+        self
+    }
+
+    /// Set a number of digits after a decimal point for aggregated values.
+    ///
+    /// By default it's the largest number of digits after a decimal point among aggregated cells,
+    /// but at least 2 for a mean.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Set an amount of first rows (not including panels) which are not aggregated.
+    ///
+    /// By default it's 1.
+    pub fn header(mut self, count: usize) -> Self {
+        self.header = count;
+        self
+    }
+
+    /// Returns an [`Object`] which locates summary rows added by the [`Aggregate`].
+    ///
+    /// The rows are known only once the [`Aggregate`] is applied to a table,
+    /// until then nothing is located.
+    pub fn summary_rows(&self) -> SummaryRows {
+        self.summary.clone()
+    }
+
+    fn function(mut self, column: usize, function: Function) -> Self {
+        self.functions.push((column, function));
+        self
+    }
+}
+
+impl Aggregate<Columns<Range<usize>>> {
+    /// Add only a total row, without grouping.
+    pub fn total() -> Self {
+        Self::group_by(Columns::new(0..0)).subtotal_label(None::<String>)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Function {
+    Sum,
+    Count,
+    Min,
+    Max,
+    Mean,
+}
+
+impl Function {
+    /// Calculates a value along with a precision of the given cells.
+    fn calculate<'a, I>(self, cells: I) -> Option<(f64, usize)>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        if self == Function::Count {
+            let count = cells.filter(|text| !strip(text).trim().is_empty()).count();
+            return Some((count as f64, 0));
+        }
+
+        let mut numbers = cells.filter_map(|text| {
+            let text = strip(text);
+            parse_number(&text).map(|n| (n, count_decimals(&text)))
+        });
+        let first = numbers.next()?;
+
+        let (value, precision, count) =
+            numbers.fold((first.0, first.1, 1), |(acc, precision, count), (n, p)| {
+                let acc = match self {
+                    Function::Min => acc.min(n),
+                    Function::Max => acc.max(n),
+                    _ => acc + n,
+                };
+
+                (acc, precision.max(p), count + 1)
+            });
+
+        match self {
+            Function::Mean => Some((value / count as f64, precision.max(MEAN_PRECISION))),
+            _ => Some((value, precision)),
+        }
+    }
+}
+
+/// Returns a number of digits after a decimal point of a number.
+fn count_decimals(text: &str) -> usize {
+    let text = text.trim();
+    match text.find('.') {
+        Some(i) => text[i + 1..]
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .count(),
+        None => 0,
+    }
+}
+
+fn format_number(value: f64, precision: usize) -> String {
+    let text = format!("{value:.precision$}");
+
+    // a value which is rounded to zero is printed without a sign
+    match text.strip_prefix('-') {
+        Some(abs) if abs.chars().all(|c| c == '0' || c == '.') => abs.to_owned(),
+        _ => text,
+    }
+}
+
+/// A layout of a table after aggregation.
+enum Line {
+    Data(usize),
+    Subtotal(Vec<String>),
+    Total(Vec<String>),
+}
+
+impl<L, R, D> TableOption<R, ColoredConfig, D> for Aggregate<L>
+where
+    L: Location<R, Coordinate = usize>,
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    fn change(mut self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let is_valid = |col: &usize| *col < count_columns;
+        let mut group_by = Vec::new();
+        for col in self.group_by.locate(records) {
+            if is_valid(&col) && !group_by.contains(&col) {
+                group_by.push(col);
+            }
+        }
+        let functions = self
+            .functions
+            .iter()
+            .copied()
+            .filter(|(col, _)| is_valid(col))
+            .collect::<Vec<_>>();
+
+        let pinned = find_pinned_rows(cfg, count_rows, count_columns, self.header);
+        let last_data_row = match (0..count_rows).rev().find(|&row| !pinned[row]) {
+            Some(row) => row,
+            None => return,
+        };
+
+        let mut lines = Vec::with_capacity(count_rows);
+        let mut data_rows = Vec::new();
+
+        let mut row = 0;
+        while row < count_rows {
+            if pinned[row] {
+                lines.push(Line::Data(row));
+                row += 1;
+                continue;
+            }
+
+            let start = row;
+            while row < count_rows && !pinned[row] {
+                row += 1;
+            }
+
+            data_rows.extend(start..row);
+
+            let groups = match self.subtotal {
+                Some(_) if !group_by.is_empty() => collect_groups(records, &group_by, start..row),
+                _ => vec![(start..row).collect()],
+            };
+
+            for group in groups {
+                lines.extend(group.iter().map(|&row| Line::Data(row)));
+
+                if let Some(label) = &self.subtotal {
+                    if !group_by.is_empty() {
+                        let summary =
+                            self.build_summary(records, &group, &group_by, &functions, label);
+                        lines.push(Line::Subtotal(summary));
+                    }
+                }
+            }
+
+            if row > last_data_row {
+                if let Some(label) = &self.total {
+                    let summary = self.build_summary(records, &data_rows, &[], &functions, label);
+                    lines.push(Line::Total(summary));
+                }
+            }
+        }
+
+        let summary = insert_lines(records, cfg, lines);
+        *self.summary.rows.borrow_mut() = summary;
+    }
+}
+
+impl<L> Aggregate<L> {
+    fn build_summary<R>(
+        &self,
+        records: &R,
+        rows: &[usize],
+        group_by: &[usize],
+        functions: &[(usize, Function)],
+        label: &str,
+    ) -> Vec<String>
+    where
+        R: Records + PeekableRecords,
+    {
+        let count_columns = records.count_columns();
+        let mut line = vec![String::new(); count_columns];
+
+        if let Some(&row) = rows.first() {
+            for &col in group_by {
+                line[col] = records.get_text((row, col)).to_owned();
+            }
+        }
+
+        let label_column = (0..count_columns)
+            .find(|col| !group_by.contains(col) && !functions.iter().any(|(c, _)| c == col));
+        if let Some(col) = label_column {
+            line[col] = label.to_owned();
+        }
+
+        for &(col, function) in functions {
+            let cells = rows.iter().map(|&row| records.get_text((row, col)));
+            if let Some((value, precision)) = function.calculate(cells) {
+                let precision = self.precision.unwrap_or(precision);
+                line[col] = format_number(value, precision);
+            }
+        }
+
+        line
+    }
+}
+
+/// Collects rows into groups by equal values in the given columns.
+fn collect_groups<R>(records: &R, columns: &[usize], rows: Range<usize>) -> Vec<Vec<usize>>
+where
+    R: PeekableRecords,
+{
+    let mut groups: Vec<(Vec<&str>, Vec<usize>)> = Vec::new();
+    for row in rows {
+        let key = columns
+            .iter()
+            .map(|&col| records.get_text((row, col)))
+            .collect::<Vec<_>>();
+
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, group)) => group.push(row),
+            None => groups.push((key, vec![row])),
+        }
+    }
+
+    groups.into_iter().map(|(_, rows)| rows).collect()
+}
+
+/// Rearranges records and configuration according to the lines, inserting summary rows.
+///
+/// Returns positions of the inserted rows.
+fn insert_lines<R>(records: &mut R, cfg: &mut ColoredConfig, lines: Vec<Line>) -> Summary
+where
+    R: Records + ExactRecords + Resizable + RecordsMut<String>,
+{
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+
+    let mut order = Vec::with_capacity(lines.len());
+    let mut moves = Vec::with_capacity(lines.len());
+    let mut next_row = count_rows;
+    for line in &lines {
+        match line {
+            Line::Data(row) => {
+                order.push(*row);
+                moves.push(Some(*row));
+            }
+            Line::Subtotal(_) | Line::Total(_) => {
+                records.push_row();
+                order.push(next_row);
+                moves.push(None);
+                next_row += 1;
+            }
+        }
+    }
+
+    move_rows_config(cfg, &moves, count_columns);
+    reorder_rows(records, &order);
+
+    let mut summary = Summary::default();
+    for (row, line) in lines.into_iter().enumerate() {
+        let texts = match line {
+            Line::Data(_) => continue,
+            Line::Subtotal(texts) => {
+                summary.subtotals.push(row);
+                texts
+            }
+            Line::Total(texts) => {
+                summary.total = Some(row);
+                texts
+            }
+        };
+
+        for (col, text) in texts.into_iter().enumerate() {
+            records.set((row, col), text);
+        }
+    }
+
+    summary
+}
+
+/// An [`Object`] which locates summary rows added by [`Aggregate`].
+///
+/// It's created by [`Aggregate::summary_rows`],
+/// and it locates the rows by positions at which they were inserted,
+/// so it's supposed to be used before rows are moved by other settings.
+#[derive(Debug, Clone, Default)]
+pub struct SummaryRows {
+    rows: Rc<RefCell<Summary>>,
+    kind: Option<SummaryKind>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Summary {
+    subtotals: Vec<usize>,
+    total: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SummaryKind {
+    Subtotal,
+    Total,
+}
+
+impl SummaryRows {
+    /// Locate only subtotal rows.
+    pub fn subtotals(&self) -> Self {
+        self.with_kind(SummaryKind::Subtotal)
+    }
+
+    /// Locate only a total row.
+    pub fn total(&self) -> Self {
+        self.with_kind(SummaryKind::Total)
+    }
+
+    fn with_kind(&self, kind: SummaryKind) -> Self {
+        Self {
+            rows: self.rows.clone(),
+            kind: Some(kind),
+        }
+    }
+}
+
+impl<R> Object<R> for SummaryRows
+where
+    R: Records + ExactRecords,
+{
+    type Iter = std::vec::IntoIter<Entity>;
+
+    fn cells(&self, records: &R) -> Self::Iter {
+        let summary = self.rows.borrow();
+
+        let subtotals = match self.kind {
+            None | Some(SummaryKind::Subtotal) => summary.subtotals.as_slice(),
+            Some(SummaryKind::Total) => &[],
+        };
+        let total = match self.kind {
+            None | Some(SummaryKind::Total) => summary.total,
+            Some(SummaryKind::Subtotal) => None,
+        };
+
+        let count_rows = records.count_rows();
+        let mut rows = subtotals
+            .iter()
+            .copied()
+            .chain(total)
+            .filter(|&row| row < count_rows)
+            .collect::<Vec<_>>();
+        rows.sort_unstable();
+
+        rows.into_iter()
+            .map(Entity::Row)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_number, Function};
+
+    #[test]
+    fn function_test() {
+        let cells = ["1", "x", "2.5", "", "-4"];
+
+        assert_eq!(
+            Function::Sum.calculate(cells.iter().copied()),
+            Some((-0.5, 1))
+        );
+        assert_eq!(
+            Function::Count.calculate(cells.iter().copied()),
+            Some((4.0, 0))
+        );
+        assert_eq!(
+            Function::Min.calculate(cells.iter().copied()),
+            Some((-4.0, 1))
+        );
+        assert_eq!(
+            Function::Max.calculate(cells.iter().copied()),
+            Some((2.5, 1))
+        );
+        assert_eq!(
+            Function::Mean.calculate(cells.iter().copied()),
+            Some((-0.5 / 3.0, 2))
+        );
+        assert_eq!(Function::Sum.calculate(["a", ""].iter().copied()), None);
+    }
+
+    #[test]
+    fn format_number_test() {
+        assert_eq!(format_number(0.1 + 0.2, 1), "0.3");
+        assert_eq!(format_number(10.0 / 3.0, 0), "3");
+        assert_eq!(format_number(10.0 / 3.0, 2), "3.33");
+        assert_eq!(format_number(-0.2, 0), "0");
+        assert_eq!(format_number(-1.5, 1), "-1.5");
+    }
+}
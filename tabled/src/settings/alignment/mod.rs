@@ -137,6 +137,14 @@ impl Alignment {
         Self::vertical(AlignmentVertical::Center)
     }
 
+    /// Decimal constructs a [`DecimalAlignment`] which aligns numbers by a given decimal point.
+    ///
+    /// [`DecimalAlignment`]: crate::settings::formatting::DecimalAlignment
+    #[cfg(feature = "std")]
+    pub const fn decimal(point: char) -> crate::settings::formatting::DecimalAlignment {
+        crate::settings::formatting::DecimalAlignment::new(point)
+    }
+
     /// Convert alignment to horizontal.
     pub const fn get_horizontal(self) -> Option<AlignmentHorizontal> {
         match self.inner {
@@ -0,0 +1,107 @@
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, ColoredConfig, Entity},
+        records::{ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
+    },
+    settings::{width::Truncate, CellOption, Color},
+};
+
+/// ColumnSettings is a set of settings of a column which are declared by a type,
+/// like an alignment, a maximum width and a color.
+///
+/// They are returned by [`Tabled::column_settings`] (which `#[derive(Tabled)]` implements
+/// by `#[tabled(align = "right", max_width = 20, color = "red")]` attributes)
+/// and are applied by [`Table::new`].
+///
+/// It can be also used as a [`CellOption`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     Table,
+///     grid::config::AlignmentHorizontal,
+///     settings::{object::Columns, ColumnSettings, Style},
+/// };
+///
+/// let settings = ColumnSettings::new()
+///     .alignment(AlignmentHorizontal::Right)
+///     .max_width(3);
+///
+/// let mut table = Table::new([("Hello", "World")]);
+/// table.with(Style::psql()).modify(Columns::first(), settings);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &st | &str  \n",
+///         "-----+-------\n",
+///         " Hel | World ",
+///     ),
+/// );
+/// ```
+///
+/// [`Tabled::column_settings`]: crate::Tabled::column_settings
+/// [`Table::new`]: crate::Table::new
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ColumnSettings {
+    alignment: Option<AlignmentHorizontal>,
+    max_width: Option<usize>,
+    color: Option<Color>,
+}
+
+impl ColumnSettings {
+    /// Creates an empty [`ColumnSettings`].
+    pub const fn new() -> Self {
+        Self {
+            alignment: None,
+            max_width: None,
+            color: None,
+        }
+    }
+
+    /// Sets a horizontal alignment of a column.
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets a maximum width of a column content,
+    /// a content which exceeds it is truncated.
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Sets a color of a column.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Checks whether there are no settings set.
+    pub fn is_empty(&self) -> bool {
+        self.alignment.is_none() && self.max_width.is_none() && self.color.is_none()
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for ColumnSettings
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        if let Some(alignment) = self.alignment {
+            cfg.set_alignment_horizontal(entity, alignment);
+        }
+
+        if let Some(width) = self.max_width {
+            CellOption::change(Truncate::new(width), records, cfg, entity);
+        }
+
+        if let Some(color) = self.color {
+            CellOption::<R, _>::change(color, records, cfg, entity);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    grid::config::{ColoredConfig, Entity},
+    settings::{CellOption, TableOption},
+};
+
+pub use crate::grid::config::Direction;
+
+impl<R> CellOption<R, ColoredConfig> for Direction {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        let _ = cfg.set_direction(entity, self);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for Direction {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let _ = cfg.set_direction(Entity::Global, self);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
@@ -0,0 +1,385 @@
+//! This module contains a [`Filter`] setting which removes rows of a [`Table`] by a condition.
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "derive", doc = "```")]
+#![cfg_attr(not(feature = "derive"), doc = "```ignore")]
+//! use tabled::{
+//!     settings::{
+//!         filter::Filter,
+//!         location::Locator,
+//!         object::{Columns, Object},
+//!         Style,
+//!     },
+//!     Table, Tabled,
+//! };
+//!
+//! #[derive(Tabled)]
+//! struct Job {
+//!     name: &'static str,
+//!     status: &'static str,
+//! }
+//!
+//! let data = [
+//!     Job { name: "build", status: "failed" },
+//!     Job { name: "lint", status: "passed" },
+//!     Job { name: "test", status: "failed" },
+//! ];
+//!
+//! let failed = Columns::single(1).intersect(Locator::by(|text| text == "failed"));
+//!
+//! let mut table = Table::new(data);
+//! table
+//!     .with(Style::markdown())
+//!     .with(Filter::cells(failed));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "| name  | status |\n",
+//!         "|-------|--------|\n",
+//!         "| build | failed |\n",
+//!         "| test  | failed |",
+//!     ),
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use core::cmp::min;
+use std::marker::PhantomData;
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        records::{
+            vec_records::{Text, VecRecords},
+            ExactRecords, PeekableRecords, Records, RecordsMut, Resizable,
+        },
+    },
+    settings::{
+        object::Object,
+        sort::{find_pinned_rows, is_panel, move_columns_config, move_rows_config},
+        TableOption,
+    },
+};
+
+/// Filter removes rows (or columns) of a [`Table`] which don't satisfy a condition.
+///
+/// The first row is considered to be a header and is always kept,
+/// see [`Filter::header`] to change the number of such rows.
+/// Panels (rows which are spanned over all columns, like [`Panel::header`]) are always kept too.
+///
+/// Per-cell settings (alignment, padding, formatting, colors, directions, spans) of the rest rows are preserved.
+///
+/// [`Table`]: crate::Table
+/// [`Panel::header`]: crate::settings::Panel::header
+#[derive(Debug)]
+pub struct Filter<F, Target> {
+    condition: F,
+    header: usize,
+    target: PhantomData<Target>,
+}
+
+impl<F> Filter<F, TargetRow> {
+    /// Keep rows for which a predicate returns `true`.
+    ///
+    /// A predicate gets a row and a header row (which is empty if there's no header).
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, settings::{filter::Filter, Style}};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "age"]);
+    /// builder.push_record(["Tom", "27"]);
+    /// builder.push_record(["Ann", "9"]);
+    /// builder.push_record(["Bob", "41"]);
+    ///
+    /// let table = builder.build()
+    ///     .with(Style::psql())
+    ///     .with(Filter::rows(|row, header| {
+    ///         let age = header.iter().position(|name| name.as_ref() == "age").unwrap();
+    ///         row[age].as_ref().parse::<usize>().unwrap() > 18
+    ///     }))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     " name | age \n\
+    ///      ------+-----\n\
+    ///      \x20Tom  | 27  \n\
+    ///      \x20Bob  | 41  "
+    /// );
+    /// ```
+    pub fn rows(predicate: F) -> Self
+    where
+        F: Fn(&[Text<String>], &[Text<String>]) -> bool,
+    {
+        Self::new(predicate)
+    }
+}
+
+impl<O> Filter<O, TargetCell> {
+    /// Keep rows which contain a cell found by a given object.
+    ///
+    /// It's the same object as you would use with [`Modify`],
+    /// so the same conditions (like [`ByCondition`]) can be used for both.
+    ///
+    /// [`Modify`]: crate::settings::Modify
+    /// [`ByCondition`]: crate::settings::location::ByCondition
+    pub fn cells(object: O) -> Self {
+        Self::new(object)
+    }
+}
+
+impl<F> Filter<F, TargetColumn> {
+    /// Keep columns for which a predicate returns `true`.
+    ///
+    /// A predicate gets an index of a column and its cells,
+    /// except the header ones and panels, so all rows can be inspected at once.
+    ///
+    /// Spans which cover removed columns are shrunk.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, settings::{filter::Filter, Style}};
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "error", "age"]);
+    /// builder.push_record(["Tom", "", "27"]);
+    /// builder.push_record(["Ann", "", "9"]);
+    ///
+    /// let table = builder.build()
+    ///     .with(Style::psql())
+    ///     .with(Filter::columns(|_, cells| cells.iter().any(|cell| !cell.as_ref().is_empty())))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     " name | age \n\
+    ///      ------+-----\n\
+    ///      \x20Tom  | 27  \n\
+    ///      \x20Ann  | 9   "
+    /// );
+    /// ```
+    pub fn columns(predicate: F) -> Self
+    where
+        F: Fn(usize, &[&Text<String>]) -> bool,
+    {
+        Self::new(predicate)
+    }
+}
+
+impl<F, Target> Filter<F, Target> {
+    fn new(condition: F) -> Self {
+        Self {
+            condition,
+            header: 1,
+            target: PhantomData,
+        }
+    }
+
+    /// Set an amount of first rows (not including panels) which are always kept.
+    ///
+    /// By default it's 1.
+    pub fn header(mut self, count: usize) -> Self {
+        self.header = count;
+        self
+    }
+}
+
+/// A marker struct for [`Filter`].
+#[derive(Debug)]
+pub struct TargetRow;
+
+/// A marker struct for [`Filter`].
+#[derive(Debug)]
+pub struct TargetCell;
+
+/// A marker struct for [`Filter`].
+#[derive(Debug)]
+pub struct TargetColumn;
+
+impl<F, D> TableOption<VecRecords<Text<String>>, ColoredConfig, D> for Filter<F, TargetRow>
+where
+    F: Fn(&[Text<String>], &[Text<String>]) -> bool,
+{
+    fn change(self, records: &mut VecRecords<Text<String>>, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let mut keep = find_pinned_rows(cfg, count_rows, count_columns, self.header);
+
+        let header = (0..count_rows)
+            .find(|&row| self.header > 0 && keep[row] && !is_panel(cfg, row, count_columns))
+            .map(|row| records[row].as_slice())
+            .unwrap_or_default();
+
+        for (row, is_kept) in keep.iter_mut().enumerate() {
+            if !*is_kept {
+                *is_kept = (self.condition)(&records[row], header);
+            }
+        }
+
+        remove_rows(records, cfg, &keep);
+    }
+}
+
+impl<F, D> TableOption<VecRecords<Text<String>>, ColoredConfig, D> for Filter<F, TargetColumn>
+where
+    F: Fn(usize, &[&Text<String>]) -> bool,
+{
+    fn change(self, records: &mut VecRecords<Text<String>>, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let pinned = find_pinned_rows(cfg, count_rows, count_columns, self.header);
+
+        let keep = (0..count_columns)
+            .map(|col| {
+                let cells = (0..count_rows)
+                    .filter(|&row| !pinned[row])
+                    .map(|row| &records[row][col])
+                    .collect::<Vec<_>>();
+
+                (self.condition)(col, &cells)
+            })
+            .collect::<Vec<_>>();
+
+        remove_columns(records, cfg, &keep);
+    }
+}
+
+impl<O, R, D> TableOption<R, ColoredConfig, D> for Filter<O, TargetCell>
+where
+    O: Object<R>,
+    R: Records + ExactRecords + Resizable,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let mut keep = find_pinned_rows(cfg, count_rows, count_columns, self.header);
+
+        for entity in self.condition.cells(records) {
+            match entity {
+                Entity::Global | Entity::Column(_) => keep.iter_mut().for_each(|row| *row = true),
+                Entity::Row(row) | Entity::Cell(row, _) => {
+                    if let Some(is_kept) = keep.get_mut(row) {
+                        *is_kept = true;
+                    }
+                }
+            }
+        }
+
+        remove_rows(records, cfg, &keep);
+    }
+}
+
+/// Removes rows which are not marked to be kept,
+/// moving configuration of the rest rows accordingly.
+pub(crate) fn remove_rows<R>(records: &mut R, cfg: &mut ColoredConfig, keep: &[bool])
+where
+    R: Records + Resizable,
+{
+    let count_rows = keep.len();
+    let count_columns = records.count_columns();
+
+    let order = (0..count_rows).filter(|&row| keep[row]).collect::<Vec<_>>();
+    if order.len() == count_rows {
+        return;
+    }
+
+    let moves = order.iter().copied().map(Some).collect::<Vec<_>>();
+    move_rows_config(cfg, &moves, count_columns);
+
+    // shrink row spans which were covering removed rows
+    for (row, &from) in order.iter().enumerate() {
+        for col in 0..count_columns {
+            if let Some(span) = cfg.get_row_span((row, col)) {
+                let end = min(from + span, count_rows);
+                let span = (from..end).filter(|&row| keep[row]).count();
+                cfg.set_row_span((row, col), span);
+            }
+        }
+    }
+
+    for row in order.len()..count_rows {
+        for col in 0..count_columns {
+            cfg.set_row_span((row, col), 1);
+            cfg.set_column_span((row, col), 1);
+        }
+    }
+
+    for row in (0..count_rows).rev() {
+        if !keep[row] {
+            records.remove_row(row);
+        }
+    }
+}
+
+/// Removes columns which are not marked to be kept,
+/// moving configuration of the rest columns accordingly.
+///
+/// A span which starts at a removed column is moved to its first kept column, along with a text.
+pub(crate) fn remove_columns<R>(records: &mut R, cfg: &mut ColoredConfig, keep: &[bool])
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String> + Resizable,
+{
+    let count_rows = records.count_rows();
+    let count_columns = keep.len();
+
+    let order = (0..count_columns)
+        .filter(|&col| keep[col])
+        .collect::<Vec<_>>();
+    if order.len() == count_columns {
+        return;
+    }
+
+    // spans are moved separately as they are not a part of a cell configuration
+    let mut spans = vec![];
+    for row in 0..count_rows {
+        for from in 0..count_columns {
+            let span = match cfg.get_column_span((row, from)) {
+                Some(span) => span,
+                None => continue,
+            };
+
+            let end = min(from + span, count_columns);
+            let first = match (from..end).find(|&col| keep[col]) {
+                Some(col) => col,
+                None => continue,
+            };
+
+            if first != from {
+                let text = records.get_text((row, from)).to_owned();
+                records.set((row, first), text);
+            }
+
+            let col = order.iter().position(|&col| col == first).unwrap_or(0);
+            let span = (from..end).filter(|&col| keep[col]).count();
+            spans.push(((row, col), span));
+        }
+
+        for col in 0..count_columns {
+            cfg.set_column_span((row, col), 1);
+        }
+    }
+
+    move_columns_config(cfg, &order, count_rows);
+
+    for (pos, span) in spans {
+        cfg.set_column_span(pos, span);
+    }
+
+    for col in order.len()..count_columns {
+        for row in 0..count_rows {
+            cfg.set_row_span((row, col), 1);
+        }
+    }
+
+    for col in (0..count_columns).rev() {
+        if !keep[col] {
+            records.remove_column(col);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, ColoredConfig, Entity, Position},
+        records::{ExactRecords, PeekableRecords, Records},
+        util::string::{get_line_width, get_lines},
+    },
+    settings::{sort::strip, CellOption, TableOption},
+};
+
+/// Aligns numbers in a column by a decimal point.
+///
+/// It pads lines of cells with spaces so the decimal points line up across a column,
+/// and aligns the cells to the right.
+/// The padding is added when a table is rendered, so a content of cells is left as it is;
+/// [`Width`] settings take it into account.
+/// Lines without a point are aligned as if the point was right after them,
+/// lines without any digits are left untouched.
+///
+/// Be aware that the points are lined up only among the cells of a column within a targeted entity,
+/// so it's supposed to be used with [`Columns`] or with a whole table.
+///
+/// It can be created by [`Alignment::decimal`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     Table,
+///     settings::{object::Columns, Alignment, Modify, Style},
+/// };
+///
+/// let data = [("apple", "1.5"), ("melon", "12.25"), ("lemon", "100"), ("grape", "0.125")];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Style::psql())
+///     .with(Modify::new(Columns::single(1)).with(Alignment::decimal('.')));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str  |    &str \n",
+///         "-------+---------\n",
+///         " apple |   1.5   \n",
+///         " melon |  12.25  \n",
+///         " lemon | 100     \n",
+///         " grape |   0.125 ",
+///     ),
+/// );
+/// ```
+///
+/// [`Columns`]: crate::settings::object::Columns
+/// [`Alignment::decimal`]: crate::settings::Alignment::decimal
+/// [`Width`]: crate::settings::Width
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DecimalAlignment {
+    point: char,
+}
+
+impl DecimalAlignment {
+    /// Creates a new [`DecimalAlignment`] with a given decimal point.
+    pub const fn new(point: char) -> Self {
+        Self { point }
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for DecimalAlignment {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        let _ = cfg.set_decimal_point(entity, Some(self.point));
+        cfg.set_alignment_horizontal(entity, AlignmentHorizontal::Right);
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for DecimalAlignment {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let _ = cfg.set_decimal_point(Entity::Global, Some(self.point));
+        cfg.set_alignment_horizontal(Entity::Global, AlignmentHorizontal::Right);
+    }
+}
+
+/// Returns texts of cells with lines padded so decimal points line up across columns.
+///
+/// Only cells which text is changed are returned.
+pub(crate) fn align_decimal_points<R>(records: &R, cfg: &ColoredConfig) -> Vec<(Position, String)>
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+
+    // cells are grouped by a column and a point
+    let mut groups: Vec<((usize, char), Vec<Position>)> = Vec::new();
+    for pos in Entity::Global.iter(count_rows, count_columns) {
+        if !cfg.is_cell_visible(pos) {
+            continue;
+        }
+
+        let point = match cfg.get_decimal_point(Entity::Cell(pos.0, pos.1)) {
+            Some(point) => point,
+            None => continue,
+        };
+
+        let key = (pos.1, point);
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, cells)) => cells.push(pos),
+            None => groups.push((key, vec![pos])),
+        }
+    }
+
+    let mut changes = Vec::new();
+    for ((_, point), cells) in groups {
+        let mut int_width = 0;
+        let mut fraction_width = 0;
+        for &pos in &cells {
+            for line in get_lines(records.get_text(pos)) {
+                if let Some((int, fraction)) = split_number(&line, point) {
+                    int_width = int_width.max(int);
+                    fraction_width = fraction_width.max(fraction);
+                }
+            }
+        }
+
+        for pos in cells {
+            let text = records.get_text(pos);
+            let lines = get_lines(text)
+                .map(|line| match split_number(&line, point) {
+                    Some((int, fraction)) => {
+                        let left = int_width - int;
+                        let right = fraction_width - fraction;
+                        let line = trim(&line);
+
+                        format!("{}{}{}", " ".repeat(left), line, " ".repeat(right))
+                    }
+                    None => line.into_owned(),
+                })
+                .collect::<Vec<_>>();
+
+            let aligned = lines.join("\n");
+            if aligned != text {
+                changes.push((pos, aligned));
+            }
+        }
+    }
+
+    changes
+}
+
+/// Returns a width of an integer and a fraction part (including a point) of a number.
+fn split_number(line: &str, point: char) -> Option<(usize, usize)> {
+    let line = trim(line);
+    let text = strip(&line);
+    if !text.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let width = get_line_width(&line);
+    let fraction = match text.find(point) {
+        Some(i) => get_line_width(&text[i..]),
+        None => 0,
+    };
+
+    Some((width - fraction, fraction))
+}
+
+#[cfg(feature = "ansi")]
+fn trim(text: &str) -> Cow<'_, str> {
+    ansi_str::AnsiStr::ansi_trim(text)
+}
+
+#[cfg(not(feature = "ansi"))]
+fn trim(text: &str) -> Cow<'_, str> {
+    Cow::Borrowed(text.trim())
+}
@@ -4,17 +4,25 @@
 //! - [`TabSize`] sets a default tab size.
 //! - [`Charset`] responsible for special char treatment.
 //! - [`Justification`] responsible for justification space of content.
+//! - [`DecimalAlignment`] aligns numbers by a decimal point.
+//! - [`NumberFormat`] sets a precision and a thousands separator of numbers.
 //!
 //! [`Alignment`]: crate::settings::Alignment
 
 mod alignment_strategy;
 mod charset;
+mod decimal_alignment;
 mod justification;
+mod number_format;
 mod tab_size;
 mod trim_strategy;
 
 pub use alignment_strategy::AlignmentStrategy;
 pub use charset::{Charset, CleanCharset};
+pub use decimal_alignment::DecimalAlignment;
 pub use justification::Justification;
+pub use number_format::NumberFormat;
 pub use tab_size::TabSize;
 pub use trim_strategy::TrimStrategy;
+
+pub(crate) use decimal_alignment::align_decimal_points;
@@ -0,0 +1,267 @@
+use std::iter;
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+        util::string::get_lines,
+    },
+    settings::{CellOption, TableOption},
+};
+
+/// Formats numbers in cells, setting a precision, a thousands separator and a decimal point.
+///
+/// Each line of a cell is formatted separately.
+/// Lines which are not plain decimal numbers (like `-1234.5`) are left untouched.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     Table,
+///     settings::{formatting::NumberFormat, object::Columns, Alignment, Modify, Style},
+/// };
+///
+/// let data = [("rent", "1200"), ("food", "345.5"), ("salary", "-4250.126")];
+///
+/// let mut table = Table::new(data);
+/// table.with(Style::psql()).with(
+///     Modify::new(Columns::single(1))
+///         .with(NumberFormat::new().precision(2).thousands(','))
+///         .with(Alignment::decimal('.')),
+/// );
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str   |      &str \n",
+///         "--------+-----------\n",
+///         " rent   |  1,200.00 \n",
+///         " food   |    345.50 \n",
+///         " salary | -4,250.13 ",
+///     ),
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NumberFormat {
+    precision: Option<usize>,
+    thousands: Option<char>,
+    point: Option<char>,
+}
+
+impl NumberFormat {
+    /// Creates a [`NumberFormat`] which doesn't change numbers.
+    pub const fn new() -> Self {
+        Self {
+            precision: None,
+            thousands: None,
+            point: None,
+        }
+    }
+
+    /// Sets a number of digits after a decimal point.
+    ///
+    /// A number is rounded half away from zero, digit by digit,
+    /// so big numbers don't lose their precision.
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets a separator of thousands.
+    pub const fn thousands(mut self, separator: char) -> Self {
+        self.thousands = Some(separator);
+        self
+    }
+
+    /// Sets a decimal point which is used instead of `.`.
+    pub const fn decimal_point(mut self, point: char) -> Self {
+        self.point = Some(point);
+        self
+    }
+
+    /// Formats a number,
+    /// returning [`None`] in case the text is not a number.
+    pub fn format(&self, text: &str) -> Option<String> {
+        let text = text.trim();
+        if !is_number(text) {
+            return None;
+        }
+
+        let (sign, number) = match text.strip_prefix(['-', '+']) {
+            Some(rest) => (&text[..1], rest),
+            None => ("", text),
+        };
+
+        let (int, fraction) = match number.find('.') {
+            Some(i) => (&number[..i], Some(&number[i + 1..])),
+            None => (number, None),
+        };
+
+        let rounded;
+        let (int, fraction) = match self.precision {
+            Some(precision) => {
+                rounded = round(int, fraction.unwrap_or_default(), precision);
+                let (int, fraction) = rounded.split_at(rounded.len() - precision);
+                let fraction = if precision > 0 { Some(fraction) } else { None };
+
+                (int, fraction)
+            }
+            None => (int, fraction),
+        };
+
+        let mut buf = String::with_capacity(number.len() * 2);
+        buf.push_str(sign);
+
+        for (i, c) in int.chars().enumerate() {
+            let rest = int.len() - i;
+            if i > 0 && rest % 3 == 0 {
+                if let Some(separator) = self.thousands {
+                    buf.push(separator);
+                }
+            }
+
+            buf.push(c);
+        }
+
+        if let Some(fraction) = fraction {
+            buf.push(self.point.unwrap_or('.'));
+            buf.push_str(fraction);
+        }
+
+        Some(buf)
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for NumberFormat
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut ColoredConfig, entity: Entity) {
+        format_numbers(records, entity, &self);
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for NumberFormat
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut ColoredConfig, _: &mut D) {
+        format_numbers(records, Entity::Global, &self);
+    }
+}
+
+fn format_numbers<R>(records: &mut R, entity: Entity, format: &NumberFormat)
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+
+    for pos in entity.iter(count_rows, count_columns) {
+        let is_valid = pos.0 < count_rows && pos.1 < count_columns;
+        if !is_valid {
+            continue;
+        }
+
+        let text = records.get_text(pos);
+
+        let mut is_changed = false;
+        let lines = get_lines(text)
+            .map(|line| match format.format(&line) {
+                Some(number) => {
+                    is_changed = true;
+                    number
+                }
+                None => line.into_owned(),
+            })
+            .collect::<Vec<_>>();
+
+        if is_changed {
+            records.set(pos, lines.join("\n"));
+        }
+    }
+}
+
+/// Rounds a number given by its integer and fraction digits (half away from zero),
+/// returning its digits with exactly `precision` digits of a fraction.
+fn round(int: &str, fraction: &str, precision: usize) -> String {
+    let mut digits = String::with_capacity(int.len() + precision + 1);
+    digits.push_str(int);
+    digits.extend(fraction.chars().chain(iter::repeat('0')).take(precision));
+
+    let round_up = matches!(fraction.as_bytes().get(precision), Some(&digit) if digit >= b'5');
+    if !round_up {
+        return digits;
+    }
+
+    let mut digits = digits.into_bytes();
+    let mut carry = true;
+    for digit in digits.iter_mut().rev() {
+        if *digit == b'9' {
+            *digit = b'0';
+        } else {
+            *digit += 1;
+            carry = false;
+            break;
+        }
+    }
+
+    if carry {
+        digits.insert(0, b'1');
+    }
+
+    // only ascii digits are there
+    String::from_utf8(digits).unwrap_or_default()
+}
+
+/// Checks whether a text is a plain decimal number, like `-1234.5`.
+fn is_number(text: &str) -> bool {
+    let text = text.strip_prefix(['-', '+']).unwrap_or(text);
+
+    let (int, fraction) = match text.find('.') {
+        Some(i) => (&text[..i], &text[i + 1..]),
+        None => (text, ""),
+    };
+
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+
+    !int.is_empty() && is_digits(int) && is_digits(fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_test() {
+        let format = NumberFormat::new().thousands(',');
+        assert_eq!(format.format("1234567").as_deref(), Some("1,234,567"));
+        assert_eq!(
+            format.format("-123456.789").as_deref(),
+            Some("-123,456.789")
+        );
+        assert_eq!(format.format(" 123 ").as_deref(), Some("123"));
+        assert_eq!(format.format("1e5"), None);
+        assert_eq!(format.format("abc"), None);
+        assert_eq!(format.format(".5"), None);
+
+        let format = NumberFormat::new()
+            .precision(2)
+            .decimal_point(',')
+            .thousands('.');
+        assert_eq!(format.format("1234.5").as_deref(), Some("1.234,50"));
+        assert_eq!(format.format("0.005").as_deref(), Some("0,01"));
+        assert_eq!(format.format("+7").as_deref(), Some("+7,00"));
+        assert_eq!(format.format("9.995").as_deref(), Some("10,00"));
+        assert_eq!(format.format("-0.125").as_deref(), Some("-0,13"));
+
+        let format = NumberFormat::new().precision(0).thousands(',');
+        assert_eq!(
+            format.format("12345678901234567891").as_deref(),
+            Some("12,345,678,901,234,567,891")
+        );
+        assert_eq!(format.format("99.5").as_deref(), Some("100"));
+        assert_eq!(format.format("1.49").as_deref(), Some("1"));
+    }
+}
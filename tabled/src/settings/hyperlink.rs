@@ -0,0 +1,121 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    settings::{CellOption, TableOption},
+};
+
+/// Hyperlink makes a cell text a clickable link, by wrapping it into an OSC 8 sequence.
+///
+/// A url is built out of a text by a given function.
+/// Each line of a cell is linked separately, and empty lines are left as they are.
+///
+/// Links are not counted in a width,
+/// and they are kept by [`Width::wrap`] and [`Width::truncate`],
+/// being closed at the end of each line and re-opened on the next one.
+///
+/// Notice that it's up to a terminal whether a link is clickable or not.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{object::{Columns, Object, Rows}, Hyperlink}};
+///
+/// let data = [("tabled", "0.16"), ("papergrid", "0.12")];
+///
+/// let mut table = Table::new(data);
+/// table.modify(
+///     Columns::first().not(Rows::first()),
+///     Hyperlink::new(|name| format!("https://crates.io/crates/{}", name)),
+/// );
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+-----------+------+\n",
+///         "| &str      | &str |\n",
+///         "+-----------+------+\n",
+///         "| \u{1b}]8;;https://crates.io/crates/tabled\u{1b}\\tabled\u{1b}]8;;\u{1b}\\    | 0.16 |\n",
+///         "+-----------+------+\n",
+///         "| \u{1b}]8;;https://crates.io/crates/papergrid\u{1b}\\papergrid\u{1b}]8;;\u{1b}\\ | 0.12 |\n",
+///         "+-----------+------+",
+///     ),
+/// );
+/// ```
+///
+/// [`Width::wrap`]: crate::settings::Width::wrap
+/// [`Width::truncate`]: crate::settings::Width::truncate
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hyperlink<F> {
+    f: F,
+}
+
+impl<F> Hyperlink<F> {
+    /// Creates a [`Hyperlink`] with a function which builds a url out of a cell text.
+    pub fn new(f: F) -> Self
+    where
+        F: FnMut(&str) -> String,
+    {
+        Self { f }
+    }
+}
+
+impl<F, R, D, C> TableOption<R, C, D> for Hyperlink<F>
+where
+    F: FnMut(&str) -> String,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut C, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+impl<F, R, C> CellOption<R, C> for Hyperlink<F>
+where
+    F: FnMut(&str) -> String,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(mut self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let is_valid_pos = pos.0 < count_rows && pos.1 < count_cols;
+            if !is_valid_pos {
+                continue;
+            }
+
+            let text = records.get_text(pos);
+            let text = text
+                .split('\n')
+                .map(|line| build_link(line, &mut self.f))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            records.set(pos, text);
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+fn build_link<F>(line: &str, f: F) -> String
+where
+    F: FnOnce(&str) -> String,
+{
+    // https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+    const OSC8: &str = "\x1b]8;;";
+    const ST: &str = "\x1b\\";
+
+    if line.is_empty() {
+        return String::new();
+    }
+
+    let url = f(line);
+    format!("{}{}{}{}{}{}", OSC8, url, ST, line, OSC8, ST)
+}
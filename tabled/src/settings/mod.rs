@@ -60,9 +60,15 @@ mod modify;
 #[cfg(feature = "std")]
 mod color;
 #[cfg(feature = "std")]
+mod column_settings;
+#[cfg(feature = "std")]
 mod concat;
 #[cfg(feature = "std")]
+mod direction;
+#[cfg(feature = "std")]
 mod duplicate;
+#[cfg(feature = "ansi")]
+mod hyperlink;
 
 pub mod style;
 
@@ -70,11 +76,17 @@ pub mod style;
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod object;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod aggregate;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod disable;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod filter;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod format;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -108,6 +120,9 @@ pub mod peaker;
 mod shadow;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod sort;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod span;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -133,7 +148,9 @@ pub use self::{
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use self::{
     color::Color,
+    column_settings::ColumnSettings,
     concat::Concat,
+    direction::Direction,
     disable::Disable,
     duplicate::Dup,
     format::Format,
@@ -147,3 +164,7 @@ pub use self::{
     themes::Theme,
     width::Width,
 };
+
+#[cfg(feature = "ansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ansi")))]
+pub use self::hyperlink::Hyperlink;
@@ -138,10 +138,14 @@ mod tests {
         assert_eq!(
             split_string_by_width("\u{1b}[31;100m😳😳🏳️\u{1b}[39m\u{1b}[49m😳🏳️", 3),
             {
-                #[cfg(feature = "ansi")]
+                #[cfg(all(feature = "ansi", not(feature = "grapheme")))]
                 {
                     "\u{1b}[31m\u{1b}[100m😳\u{1b}[39m\u{1b}[49m�\n\u{1b}[31m\u{1b}[100m🏳\u{fe0f}\u{1b}[39m\u{1b}[49m😳\n🏳\u{fe0f}"
                 }
+                #[cfg(all(feature = "ansi", feature = "grapheme"))]
+                {
+                    "\u{1b}[31m\u{1b}[100m😳\u{1b}[39m\u{1b}[49m�\n\u{1b}[31m\u{1b}[100m🏳\u{fe0f}\u{1b}[39m\u{1b}[49m�\n🏳\u{fe0f}"
+                }
                 #[cfg(not(feature = "ansi"))]
                 {
                     "\u{1b}[31\n;10\n0m�\n😳🏳\n\u{fe0f}\u{1b}[39\nm\u{1b}[4\n9m�\n🏳\u{fe0f}"
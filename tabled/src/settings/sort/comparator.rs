@@ -0,0 +1,108 @@
+use core::cmp::Ordering;
+
+/// A trait which is responsible for comparing 2 cells of a column while sorting a [`Table`].
+///
+/// It's implemented for any `Fn(&str, &str) -> Ordering`,
+/// so a closure can be used as a comparator.
+///
+/// [`Table`]: crate::Table
+pub trait Comparator {
+    /// Compare content of 2 cells.
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering;
+
+    /// Compare content of 2 cells for a descending sort.
+    ///
+    /// By default it's a reversed [`Comparator::compare`].
+    fn compare_descending(&self, lhs: &str, rhs: &str) -> Ordering {
+        self.compare(lhs, rhs).reverse()
+    }
+}
+
+impl<F> Comparator for F
+where
+    F: Fn(&str, &str) -> Ordering,
+{
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        (self)(lhs, rhs)
+    }
+}
+
+/// A comparator which compares cells as strings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lexicographic;
+
+impl Comparator for Lexicographic {
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        strip(lhs).cmp(&strip(rhs))
+    }
+}
+
+/// A comparator which compares cells as numbers.
+///
+/// Cells which can't be parsed as a number are put after the numbers in both directions,
+/// and are compared as strings among each other.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Numeric;
+
+impl Comparator for Numeric {
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        compare_numbers(lhs, rhs, false)
+    }
+
+    fn compare_descending(&self, lhs: &str, rhs: &str) -> Ordering {
+        compare_numbers(lhs, rhs, true)
+    }
+}
+
+fn compare_numbers(lhs: &str, rhs: &str, descending: bool) -> Ordering {
+    let lhs = strip(lhs);
+    let rhs = strip(rhs);
+
+    match (parse_number(&lhs), parse_number(&rhs)) {
+        (Some(a), Some(b)) if descending => b.partial_cmp(&a).unwrap_or(Ordering::Equal),
+        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => lhs.cmp(&rhs),
+    }
+}
+
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    text.trim().parse::<f64>().ok().filter(|n| !n.is_nan())
+}
+
+#[cfg(feature = "ansi")]
+pub(crate) fn strip(text: &str) -> std::borrow::Cow<'_, str> {
+    ansi_str::AnsiStr::ansi_strip(text)
+}
+
+#[cfg(not(feature = "ansi"))]
+pub(crate) fn strip(text: &str) -> std::borrow::Cow<'_, str> {
+    std::borrow::Cow::Borrowed(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_test() {
+        assert_eq!(Numeric.compare("2", "10"), Ordering::Less);
+        assert_eq!(Numeric.compare("-1.5", "-2"), Ordering::Greater);
+        assert_eq!(Numeric.compare(" 3 ", "3.0"), Ordering::Equal);
+        assert_eq!(Numeric.compare("1", "abc"), Ordering::Less);
+        assert_eq!(Numeric.compare("", "1"), Ordering::Greater);
+        assert_eq!(Numeric.compare("abc", "abd"), Ordering::Less);
+
+        assert_eq!(Numeric.compare_descending("2", "10"), Ordering::Greater);
+        assert_eq!(Numeric.compare_descending("1", "abc"), Ordering::Less);
+        assert_eq!(Numeric.compare_descending("", "1"), Ordering::Greater);
+    }
+
+    #[test]
+    fn lexicographic_test() {
+        assert_eq!(Lexicographic.compare("2", "10"), Ordering::Greater);
+        assert_eq!(Lexicographic.compare("a", "b"), Ordering::Less);
+        assert_eq!(Lexicographic.compare("a", "a"), Ordering::Equal);
+    }
+}
@@ -0,0 +1,544 @@
+//! This module contains a [`Sort`] setting which reorders rows of a [`Table`].
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "derive", doc = "```")]
+#![cfg_attr(not(feature = "derive"), doc = "```ignore")]
+//! use tabled::{
+//!     settings::{object::Columns, sort::Sort, Panel, Style},
+//!     Table, Tabled,
+//! };
+//!
+//! #[derive(Tabled)]
+//! struct Person {
+//!     name: &'static str,
+//!     age: usize,
+//! }
+//!
+//! let data = [
+//!     Person { name: "Ann", age: 9 },
+//!     Person { name: "Bob", age: 41 },
+//!     Person { name: "Tom", age: 27 },
+//! ];
+//!
+//! let mut table = Table::new(data);
+//! table
+//!     .with(Panel::header("Persons"))
+//!     .with(Sort::column(Columns::single(1)).numeric().descending())
+//!     .with(Style::modern());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "┌──────┬─────┐\n",
+//!         "│ Persons    │\n",
+//!         "├──────┼─────┤\n",
+//!         "│ name │ age │\n",
+//!         "├──────┼─────┤\n",
+//!         "│ Bob  │ 41  │\n",
+//!         "├──────┼─────┤\n",
+//!         "│ Tom  │ 27  │\n",
+//!         "├──────┼─────┤\n",
+//!         "│ Ann  │ 9   │\n",
+//!         "└──────┴─────┘",
+//!     ),
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+mod comparator;
+
+pub use comparator::{Comparator, Lexicographic, Numeric};
+
+pub(crate) use comparator::{parse_number, strip};
+
+use core::cmp::min;
+
+use crate::{
+    grid::{
+        ansi::ANSIBuf,
+        colors::Colors,
+        config::{
+            AlignmentHorizontal, AlignmentVertical, ColoredConfig, Direction, Entity, Formatting,
+            Indent, Position, Sides,
+        },
+        records::{ExactRecords, PeekableRecords, Records, Resizable},
+    },
+    settings::{location::Location, TableOption},
+};
+
+/// Sort reorders rows of a [`Table`] by a content of a given column.
+///
+/// The first row is considered to be a header and is kept in place,
+/// see [`Sort::header`] to change the number of such rows.
+/// Panels (rows which are spanned over all columns, like [`Panel::header`]) are kept in place too,
+/// rows in between them are sorted separately.
+///
+/// Rows which are connected by a row span are moved together,
+/// their order is defined by a first row of the group.
+/// Per-cell settings (alignment, padding, formatting, colors, directions, column spans) are moved along with the data.
+///
+/// The sort is stable.
+///
+/// # Example
+///
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use tabled::{Table, settings::{sort::Sort, object::Columns}};
+///
+/// let data = [("Tom", 27), ("Ann", 9), ("Bob", 41)];
+///
+/// let table = Table::new(data)
+///     .with(Sort::column(Columns::first()))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+------+-----+\n\
+///      | &str | i32 |\n\
+///      +------+-----+\n\
+///      | Ann  | 9   |\n\
+///      +------+-----+\n\
+///      | Bob  | 41  |\n\
+///      +------+-----+\n\
+///      | Tom  | 27  |\n\
+///      +------+-----+"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Panel::header`]: crate::settings::Panel::header
+#[derive(Debug, Clone)]
+pub struct Sort<L, C = Lexicographic> {
+    locator: L,
+    comparator: C,
+    descending: bool,
+    header: usize,
+}
+
+impl<L> Sort<L, Lexicographic> {
+    /// Sort rows by a column found by a given locator.
+    ///
+    /// If locator finds a few columns the first one is used.
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// [`Columns`]: crate::settings::object::Columns
+    /// [`Column`]: crate::settings::object::Column
+    /// [`FirstColumn`]: crate::settings::object::FirstColumn
+    /// [`LastColumn`]: crate::settings::object::LastColumn
+    /// [`ByColumnName`]: crate::settings::location::ByColumnName
+    pub fn column(locator: L) -> Self {
+        Self {
+            locator,
+            comparator: Lexicographic,
+            descending: false,
+            header: 1,
+        }
+    }
+}
+
+impl<L, C> Sort<L, C> {
+    /// Compare cells as strings (default).
+    pub fn lexicographic(self) -> Sort<L, Lexicographic> {
+        self.by(Lexicographic)
+    }
+
+    /// Compare cells as numbers.
+    ///
+    /// Cells which can't be parsed as numbers are put after the numbers,
+    /// including a descending order.
+    pub fn numeric(self) -> Sort<L, Numeric> {
+        self.by(Numeric)
+    }
+
+    /// Set a custom comparator.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{sort::Sort, object::Columns}};
+    ///
+    /// let data = ["tabled", "is", "great"];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Sort::column(Columns::first()).by(|a: &str, b: &str| a.len().cmp(&b.len())))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--------+\n\
+    ///      | &str   |\n\
+    ///      +--------+\n\
+    ///      | is     |\n\
+    ///      +--------+\n\
+    ///      | great  |\n\
+    ///      +--------+\n\
+    ///      | tabled |\n\
+    ///      +--------+"
+    /// );
+    /// ```
+    pub fn by<F>(self, comparator: F) -> Sort<L, F>
+    where
+        F: Comparator,
+    {
+        Sort {
+            locator: self.locator,
+            comparator,
+            descending: self.descending,
+            header: self.header,
+        }
+    }
+
+    /// Sort in ascending order (default).
+    pub fn ascending(mut self) -> Self {
+        self.descending = false;
+        self
+    }
+
+    /// Sort in descending order.
+    ///
+    /// Cells are compared by [`Comparator::compare_descending`].
+    pub fn descending(mut self) -> Self {
+        self.descending = true;
+        self
+    }
+
+    /// Set an amount of first rows (not including panels) which are kept in place.
+    ///
+    /// By default it's 1.
+    pub fn header(mut self, count: usize) -> Self {
+        self.header = count;
+        self
+    }
+}
+
+impl<L, C, R, D> TableOption<R, ColoredConfig, D> for Sort<L, C>
+where
+    L: Location<R, Coordinate = usize>,
+    C: Comparator,
+    R: Records + ExactRecords + PeekableRecords + Resizable,
+{
+    fn change(mut self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let column = self.locator.locate(records).into_iter().next();
+        let column = match column {
+            Some(column) if column < count_columns => column,
+            _ => return,
+        };
+
+        let pinned = find_pinned_rows(cfg, count_rows, count_columns, self.header);
+
+        let mut order = (0..count_rows).collect::<Vec<_>>();
+        let mut row = 0;
+        while row < count_rows {
+            if pinned[row] {
+                row += 1;
+                continue;
+            }
+
+            let start = row;
+            while row < count_rows && !pinned[row] {
+                row += 1;
+            }
+
+            let blocks = find_row_blocks(cfg, (start, row), count_columns);
+            let mut blocks = blocks
+                .into_iter()
+                .map(|block| (records.get_text((block.0, column)), block))
+                .collect::<Vec<_>>();
+
+            blocks.sort_by(|(lhs, _), (rhs, _)| match self.descending {
+                true => self.comparator.compare_descending(lhs, rhs),
+                false => self.comparator.compare(lhs, rhs),
+            });
+
+            let sorted = blocks
+                .into_iter()
+                .flat_map(|(_, (start, end))| start..end)
+                .collect::<Vec<_>>();
+
+            order[start..row].copy_from_slice(&sorted);
+        }
+
+        let is_changed = order.iter().enumerate().any(|(i, &row)| i != row);
+        if !is_changed {
+            return;
+        }
+
+        let moves = order.iter().copied().map(Some).collect::<Vec<_>>();
+        move_rows_config(cfg, &moves, count_columns);
+        reorder_rows(records, &order);
+    }
+}
+
+/// Rows which are not supposed to be moved.
+///
+/// It's a number of first rows and all panels.
+pub(crate) fn find_pinned_rows(
+    cfg: &ColoredConfig,
+    count_rows: usize,
+    count_columns: usize,
+    header: usize,
+) -> Vec<bool> {
+    let mut pinned = vec![false; count_rows];
+    let mut header = header;
+    for (row, is_pinned) in pinned.iter_mut().enumerate() {
+        if is_panel(cfg, row, count_columns) {
+            *is_pinned = true;
+            continue;
+        }
+
+        if header > 0 {
+            header -= 1;
+            *is_pinned = true;
+        }
+    }
+
+    pinned
+}
+
+pub(crate) fn is_panel(cfg: &ColoredConfig, row: usize, count_columns: usize) -> bool {
+    count_columns > 1 && cfg.get_column_span((row, 0)) == Some(count_columns)
+}
+
+/// Splits a range of rows into groups which are connected by row spans.
+pub(crate) fn find_row_blocks(
+    cfg: &ColoredConfig,
+    (start, end): (usize, usize),
+    count_columns: usize,
+) -> Vec<(usize, usize)> {
+    let mut blocks = vec![];
+
+    let mut row = start;
+    while row < end {
+        let mut block_end = row + 1;
+        let mut i = row;
+        while i < block_end {
+            for col in 0..count_columns {
+                if let Some(span) = cfg.get_row_span((i, col)) {
+                    block_end = block_end.max(min(i + span, end));
+                }
+            }
+
+            i += 1;
+        }
+
+        blocks.push((row, block_end));
+        row = block_end;
+    }
+
+    blocks
+}
+
+/// Rearranges rows so the row `i` will contain a row which was at `order[i]`.
+pub(crate) fn reorder_rows<R>(records: &mut R, order: &[usize])
+where
+    R: Resizable,
+{
+    for i in 0..order.len() {
+        let mut row = order[i];
+        while row < i {
+            row = order[row];
+        }
+
+        if row != i {
+            records.swap_row(i, row);
+        }
+    }
+}
+
+/// A resolved configuration of a particular cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CellConfig {
+    padding: Sides<Indent>,
+    padding_color: Sides<Option<ANSIBuf>>,
+    alignment_h: AlignmentHorizontal,
+    alignment_v: AlignmentVertical,
+    formatting: Formatting,
+    justification: char,
+    justification_color: Option<ANSIBuf>,
+    color: Option<ANSIBuf>,
+    direction: Option<Direction>,
+    column_span: Option<usize>,
+    row_span: Option<usize>,
+}
+
+impl CellConfig {
+    fn get(cfg: &ColoredConfig, pos: Position) -> Self {
+        let entity = Entity::Cell(pos.0, pos.1);
+        let color = if cfg.get_colors().is_empty() {
+            None
+        } else {
+            cfg.get_colors().get_color(pos).cloned()
+        };
+
+        Self {
+            padding: cfg.get_padding(entity),
+            padding_color: cfg.get_padding_color(entity),
+            alignment_h: *cfg.get_alignment_horizontal(entity),
+            alignment_v: *cfg.get_alignment_vertical(entity),
+            formatting: *cfg.get_formatting(entity),
+            justification: cfg.get_justification(entity),
+            justification_color: cfg.get_justification_color(entity).cloned(),
+            color,
+            direction: cfg.get_direction(entity),
+            column_span: cfg.get_column_span(pos),
+            row_span: cfg.get_row_span(pos),
+        }
+    }
+
+    /// A configuration a new cell in a given column would have.
+    fn new_in_column(cfg: &ColoredConfig, col: usize) -> Self {
+        let entity = Entity::Column(col);
+
+        Self {
+            padding: cfg.get_padding(entity),
+            padding_color: cfg.get_padding_color(entity),
+            alignment_h: *cfg.get_alignment_horizontal(entity),
+            alignment_v: *cfg.get_alignment_vertical(entity),
+            formatting: *cfg.get_formatting(entity),
+            justification: cfg.get_justification(entity),
+            justification_color: cfg.get_justification_color(entity).cloned(),
+            color: None,
+            direction: cfg.get_direction(entity),
+            column_span: None,
+            row_span: None,
+        }
+    }
+
+    /// Sets the configuration to a cell, touching only the properties which differ from `current`.
+    fn set(self, cfg: &mut ColoredConfig, pos: Position, current: &Self) {
+        let entity = Entity::Cell(pos.0, pos.1);
+
+        if self.padding != current.padding {
+            cfg.set_padding(entity, self.padding);
+        }
+
+        if self.padding_color != current.padding_color {
+            cfg.set_padding_color(entity, self.padding_color);
+        }
+
+        if self.alignment_h != current.alignment_h {
+            cfg.set_alignment_horizontal(entity, self.alignment_h);
+        }
+
+        if self.alignment_v != current.alignment_v {
+            cfg.set_alignment_vertical(entity, self.alignment_v);
+        }
+
+        if self.formatting != current.formatting {
+            cfg.set_formatting(entity, self.formatting);
+        }
+
+        if self.justification != current.justification {
+            cfg.set_justification(entity, self.justification);
+        }
+
+        if self.justification_color != current.justification_color {
+            cfg.set_justification_color(entity, self.justification_color);
+        }
+
+        if self.color != current.color {
+            let _ = match self.color {
+                Some(color) => cfg.set_color(entity, color),
+                None => cfg.remove_color(entity),
+            };
+        }
+
+        if self.direction != current.direction {
+            if let Some(direction) = self.direction {
+                let _ = cfg.set_direction(entity, direction);
+            }
+        }
+
+        cfg.set_column_span(pos, self.column_span.unwrap_or(1));
+        cfg.set_row_span(pos, self.row_span.unwrap_or(1));
+    }
+}
+
+/// Moves a cell configuration so the row `i` will get a configuration of row `order[i]`.
+///
+/// `None` means the row `i` is a new one, so it gets a configuration of its columns.
+/// Rows beyond `order` are not touched.
+pub(crate) fn move_rows_config(
+    cfg: &mut ColoredConfig,
+    order: &[Option<usize>],
+    count_columns: usize,
+) {
+    let mut changes = vec![];
+    for (row, &from) in order.iter().enumerate() {
+        if from == Some(row) {
+            continue;
+        }
+
+        for col in 0..count_columns {
+            let current = CellConfig::get(cfg, (row, col));
+            let config = match from {
+                Some(from) => CellConfig::get(cfg, (from, col)),
+                None => CellConfig::new_in_column(cfg, col),
+            };
+
+            changes.push(((row, col), config, current));
+        }
+    }
+
+    for (pos, config, current) in changes {
+        config.set(cfg, pos, &current);
+    }
+}
+
+/// Moves a cell configuration so the column `i` will get a configuration of column `order[i]`.
+///
+/// Columns beyond `order` are not touched.
+pub(crate) fn move_columns_config(cfg: &mut ColoredConfig, order: &[usize], count_rows: usize) {
+    let mut changes = vec![];
+    for (col, &from) in order.iter().enumerate() {
+        if from == col {
+            continue;
+        }
+
+        for row in 0..count_rows {
+            let current = CellConfig::get(cfg, (row, col));
+            let config = CellConfig::get(cfg, (row, from));
+
+            changes.push(((row, col), config, current));
+        }
+    }
+
+    for (pos, config, current) in changes {
+        config.set(cfg, pos, &current);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::records::vec_records::VecRecords;
+
+    use super::reorder_rows;
+
+    #[test]
+    fn test_reorder_rows() {
+        assert_eq!(reorder(vec![0, 1, 2, 3], &[0, 1, 2, 3]), [0, 1, 2, 3]);
+        assert_eq!(reorder(vec![0, 1, 2, 3], &[3, 2, 1, 0]), [3, 2, 1, 0]);
+        assert_eq!(reorder(vec![0, 1, 2, 3], &[1, 2, 3, 0]), [1, 2, 3, 0]);
+        assert_eq!(reorder(vec![0, 1, 2, 3], &[2, 0, 3, 1]), [2, 0, 3, 1]);
+        assert_eq!(reorder(vec![0, 1, 2, 3], &[0, 3, 1, 2]), [0, 3, 1, 2]);
+    }
+
+    fn reorder(data: Vec<usize>, order: &[usize]) -> Vec<usize> {
+        let data = data.into_iter().map(|i| vec![i]).collect();
+        let mut records = VecRecords::new(data);
+        reorder_rows(&mut records, order);
+
+        let data: Vec<Vec<usize>> = records.into();
+        data.into_iter().map(|row| row[0]).collect()
+    }
+}
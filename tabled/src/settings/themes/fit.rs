@@ -0,0 +1,413 @@
+//! Module contains [`Fit`] setting.
+
+use std::borrow::Cow;
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, SpannedConfig},
+        dimension::{CompleteDimensionVecRecords, SpannedGridDimension},
+        records::{
+            EmptyRecords, ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut,
+            Resizable,
+        },
+        util::string::{get_line_width, get_lines},
+    },
+    settings::{
+        measurement::Measurement, split::Split, width::Truncate, width::Wrap, CellOption, Rotate,
+        TableOption, Width,
+    },
+};
+
+/// A default minimum width of a column content used by [`Fit`].
+const DEFAULT_MIN_WIDTH: usize = 5;
+
+/// Fit changes a table so it would not exceed a given width.
+///
+/// The widest columns are decreased first.
+/// Columns with a prose (text which has spaces) are wrapped,
+/// while columns with identifiers, numbers and alike are truncated.
+///
+/// A column is never decreased below its minimum width (5 by default).
+///
+/// If the table can't fit even by its minimum widths
+/// it is rotated (see [`Rotate`]), and if it still doesn't help
+/// it is split by columns (see [`Split::column`]).
+///
+/// It's constructed by [`Layout::fit`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{themes::Layout, Style}};
+///
+/// let data = [
+///     ("1f3a7c", "A quick brown fox jumps over the lazy dog"),
+///     ("9d0e2b", "Lorem ipsum dolor sit amet"),
+/// ];
+///
+/// let table = Table::new(data)
+///     .with(Style::modern())
+///     .with(Layout::fit(30))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "┌────────┬───────────────────┐\n",
+///         "│ &str   │ &str              │\n",
+///         "├────────┼───────────────────┤\n",
+///         "│ 1f3a7c │ A quick brown fox │\n",
+///         "│        │ jumps over the    │\n",
+///         "│        │ lazy dog          │\n",
+///         "├────────┼───────────────────┤\n",
+///         "│ 9d0e2b │ Lorem ipsum dolor │\n",
+///         "│        │ sit amet          │\n",
+///         "└────────┴───────────────────┘",
+///     ),
+/// );
+/// ```
+///
+/// [`Layout::fit`]: crate::settings::themes::Layout::fit
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fit<W = usize> {
+    width: W,
+    min_width: usize,
+    columns: Vec<(usize, usize)>,
+    fallback: bool,
+}
+
+impl<W> Fit<W> {
+    /// Creates a new [`Fit`] setting.
+    pub fn new(width: W) -> Self
+    where
+        W: Measurement<Width>,
+    {
+        Self {
+            width,
+            min_width: DEFAULT_MIN_WIDTH,
+            columns: Vec::new(),
+            fallback: true,
+        }
+    }
+
+    /// Sets a minimum width of a content for all columns.
+    pub fn min_width(mut self, width: usize) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Sets a minimum width of a content for a particular column.
+    ///
+    /// It overrides [`Fit::min_width`].
+    pub fn column_min_width(mut self, column: usize, width: usize) -> Self {
+        self.columns.retain(|&(col, _)| col != column);
+        self.columns.push((column, width));
+        self
+    }
+
+    /// Sets whether a table can be rotated or split in case it doesn't fit by minimum widths.
+    ///
+    /// It's on by default.
+    pub fn fallback(mut self, on: bool) -> Self {
+        self.fallback = on;
+        self
+    }
+
+    fn get_min_width(&self, column: usize) -> usize {
+        self.columns
+            .iter()
+            .find(|&&(col, _)| col == column)
+            .map_or(self.min_width, |&(_, width)| width)
+    }
+}
+
+impl<W, R> TableOption<R, ColoredConfig, CompleteDimensionVecRecords<'_>> for Fit<W>
+where
+    W: Measurement<Width>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String> + Resizable,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
+{
+    fn change(
+        self,
+        records: &mut R,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    ) {
+        if records.count_rows() == 0 || records.count_columns() == 0 {
+            return;
+        }
+
+        let width = self.width.measure(&*records, cfg);
+
+        let widths = SpannedGridDimension::width(&*records, cfg);
+        if get_total_width(&widths, cfg) <= width {
+            return;
+        }
+
+        let mut mins = (0..records.count_columns())
+            .map(|col| self.get_min_width(col))
+            .collect::<Vec<_>>();
+
+        if self.fallback {
+            let min_widths = get_min_widths(&*records, cfg, &widths, &mins);
+            if get_total_width(&min_widths, cfg) > width {
+                mins = fallback(records, cfg, width, mins, self.min_width);
+            }
+        }
+
+        let widths = SpannedGridDimension::width(&*records, cfg);
+        if get_total_width(&widths, cfg) <= width {
+            dims.set_widths(widths);
+            return;
+        }
+
+        let min_widths = get_min_widths(&*records, cfg, &widths, &mins);
+        let prose = (0..records.count_columns())
+            .map(|col| is_prose(&*records, col))
+            .collect::<Vec<_>>();
+
+        let mut fitted = widths.clone();
+        decrease_widths(&mut fitted, &min_widths, &prose, cfg, width);
+
+        fit_cells(records, cfg, &widths, &fitted, &prose);
+
+        dims.set_widths(fitted);
+    }
+}
+
+/// Rotates or splits a table.
+///
+/// Returns minimum widths of the new columns.
+fn fallback<R>(
+    records: &mut R,
+    cfg: &mut ColoredConfig,
+    width: usize,
+    mins: Vec<usize>,
+    default_min: usize,
+) -> Vec<usize>
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String> + Resizable,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
+{
+    transpose(records);
+
+    // column minimums are not applicable after rotation
+    let rotated_mins = vec![default_min; records.count_columns()];
+    let widths = SpannedGridDimension::width(&*records, cfg);
+    let min_widths = get_min_widths(&*records, cfg, &widths, &rotated_mins);
+    if get_total_width(&min_widths, cfg) <= width {
+        return rotated_mins;
+    }
+
+    transpose(records);
+
+    let widths = SpannedGridDimension::width(&*records, cfg);
+    let min_widths = get_min_widths(&*records, cfg, &widths, &mins);
+
+    let count_columns = records.count_columns();
+    let section = (1..count_columns)
+        .rev()
+        .find(|&section| {
+            let widths = merge_sections(&min_widths, section);
+            get_total_width(&widths, cfg) <= width
+        })
+        .unwrap_or(1);
+
+    Split::column(section)
+        .concat()
+        .change(records, &mut (), &mut ());
+
+    merge_sections(&mins, section)
+}
+
+fn merge_sections(list: &[usize], section: usize) -> Vec<usize> {
+    (0..section)
+        .map(|col| {
+            (col..list.len())
+                .step_by(section)
+                .map(|i| list[i])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+fn transpose<R>(records: &mut R)
+where
+    R: Records + ExactRecords + Resizable,
+{
+    Rotate::Left.change(records, &mut (), &mut ());
+    Rotate::Bottom.change(records, &mut (), &mut ());
+}
+
+fn get_min_widths<R>(
+    records: R,
+    cfg: &SpannedConfig,
+    widths: &[usize],
+    mins: &[usize],
+) -> Vec<usize>
+where
+    R: Records + ExactRecords,
+{
+    let shape = (records.count_rows(), records.count_columns());
+    let paddings = SpannedGridDimension::width(EmptyRecords::from(shape), cfg);
+
+    widths
+        .iter()
+        .zip(paddings)
+        .zip(mins)
+        .map(|((&width, padding), min)| core::cmp::min(width, padding + min))
+        .collect()
+}
+
+fn get_total_width(widths: &[usize], cfg: &SpannedConfig) -> usize {
+    let margin = cfg.get_margin();
+    widths.iter().sum::<usize>()
+        + cfg.count_vertical(widths.len())
+        + margin.left.size
+        + margin.right.size
+}
+
+/// Checks whether a column mostly consists of a text with spaces.
+///
+/// The first row is not considered as it's likely a header.
+fn is_prose<R>(records: R, column: usize) -> bool
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    let skip = if records.count_rows() > 1 { 1 } else { 0 };
+
+    let mut count_cells = 0;
+    let mut count_prose = 0;
+    for row in skip..records.count_rows() {
+        let text = records.get_text((row, column)).trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        count_cells += 1;
+
+        if text.contains(char::is_whitespace) {
+            count_prose += 1;
+        }
+    }
+
+    count_prose > 0 && count_prose * 2 >= count_cells
+}
+
+fn decrease_widths(
+    widths: &mut [usize],
+    min_widths: &[usize],
+    prose: &[bool],
+    cfg: &SpannedConfig,
+    width: usize,
+) {
+    let mut total = get_total_width(widths, cfg);
+    while total > width {
+        let col = (0..widths.len())
+            .filter(|&col| widths[col] > min_widths[col])
+            .max_by_key(|&col| (widths[col], prose[col], core::cmp::Reverse(col)));
+
+        let col = match col {
+            Some(col) => col,
+            None => break,
+        };
+
+        widths[col] -= 1;
+        total -= 1;
+    }
+}
+
+fn fit_cells<R>(
+    records: &mut R,
+    cfg: &mut ColoredConfig,
+    widths: &[usize],
+    fitted: &[usize],
+    prose: &[bool],
+) where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
+{
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+
+    for (col, &is_prose) in prose.iter().enumerate() {
+        for row in 0..count_rows {
+            let pos = (row, col);
+            if !cfg.is_cell_visible(pos) {
+                continue;
+            }
+
+            let span = cfg.get_column_span(pos).unwrap_or(1);
+            let end = core::cmp::min(col + span, count_columns);
+
+            let original = sum_width(cfg, widths, col, end, count_columns);
+            let width = sum_width(cfg, fitted, col, end, count_columns);
+            if width >= original {
+                continue;
+            }
+
+            let padding = cfg.get_padding(pos.into());
+            let width = width.saturating_sub(padding.left.size + padding.right.size);
+
+            if is_prose {
+                let text = wrap_prose(records.get_text(pos), width);
+                records.set(pos, text);
+            } else {
+                let truncate = Truncate::new(width).suffix("...").multiline(true);
+                CellOption::change(truncate, records, cfg, pos.into());
+            }
+        }
+    }
+}
+
+/// Wraps a text keeping words,
+/// so a space a line is broken at is not carried to the next line.
+fn wrap_prose(text: &str, width: usize) -> String {
+    let mut lines = Vec::new();
+    for line in get_lines(text) {
+        if get_line_width(&line) <= width {
+            lines.push(line.into_owned());
+            continue;
+        }
+
+        let wrapped = Wrap::wrap(&line, width, true);
+        for (i, part) in get_lines(&wrapped).enumerate() {
+            let part = match i {
+                0 => part.into_owned(),
+                _ => strip_space(&part).into_owned(),
+            };
+
+            lines.push(part);
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "ansi")]
+fn strip_space(text: &str) -> Cow<'_, str> {
+    ansi_str::AnsiStr::ansi_strip_prefix(text, " ").unwrap_or(Cow::Borrowed(text))
+}
+
+#[cfg(not(feature = "ansi"))]
+fn strip_space(text: &str) -> Cow<'_, str> {
+    Cow::Borrowed(text.strip_prefix(' ').unwrap_or(text))
+}
+
+fn sum_width(
+    cfg: &SpannedConfig,
+    widths: &[usize],
+    start: usize,
+    end: usize,
+    count_columns: usize,
+) -> usize {
+    let borders = (start + 1..end)
+        .filter(|&i| cfg.has_vertical(i, count_columns))
+        .count();
+
+    widths[start..end].iter().sum::<usize>() + borders
+}
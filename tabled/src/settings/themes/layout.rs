@@ -7,7 +7,7 @@ use crate::{
         config::{AlignmentHorizontal, AlignmentVertical},
         records::{RecordsMut, Resizable},
     },
-    settings::{Alignment, Rotate, TableOption},
+    settings::{measurement::Measurement, themes::Fit, Alignment, Rotate, TableOption, Width},
 };
 
 /// Layout can be used to move header to a specific corner.
@@ -35,6 +35,16 @@ impl Layout {
             orientation,
         }
     }
+
+    /// Construct a [`Fit`] setting which fits a table into a given width.
+    ///
+    /// See [`Fit`].
+    pub fn fit<W>(width: W) -> Fit<W>
+    where
+        W: Measurement<Width>,
+    {
+        Fit::new(width)
+    }
 }
 
 impl<R, C, D> TableOption<R, C, D> for Layout
@@ -4,10 +4,12 @@
 
 mod colorization;
 mod column_names;
+mod fit;
 mod layout;
 mod theme;
 
 pub use colorization::{Colorization, ExactColorization};
 pub use column_names::ColumnNames;
+pub use fit::Fit;
 pub use layout::Layout;
 pub use theme::Theme;
@@ -1,7 +1,9 @@
 use crate::{
-    grid::config::SpannedConfig,
+    grid::config::{ColoredConfig, SpannedConfig},
     grid::dimension::SpannedGridDimension,
-    grid::records::{IntoRecords, Records},
+    grid::records::{ExactRecords, IntoRecords, PeekableRecords, Records},
+    grid::util::string::get_text_width,
+    settings::formatting::align_decimal_points,
 };
 
 pub(crate) fn get_table_widths<R>(records: R, cfg: &SpannedConfig) -> Vec<usize>
@@ -12,12 +14,32 @@ where
     SpannedGridDimension::width(records, cfg)
 }
 
-pub(crate) fn get_table_widths_with_total<R>(records: R, cfg: &SpannedConfig) -> (Vec<usize>, usize)
+/// Returns widths of columns and a total width of a table
+/// as it would be rendered, including a padding of aligned decimal points.
+pub(crate) fn get_table_widths_with_total<R>(
+    records: &R,
+    cfg: &ColoredConfig,
+) -> (Vec<usize>, usize)
 where
-    R: Records,
-    <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
 {
-    let widths = SpannedGridDimension::width(records, cfg);
+    let mut widths = SpannedGridDimension::width(records, cfg);
+
+    if cfg.has_decimal_points() {
+        for (pos, text) in align_decimal_points(records, cfg) {
+            let is_spanned = matches!(cfg.get_column_span(pos), Some(span) if span > 1);
+            if is_spanned {
+                continue;
+            }
+
+            let padding = cfg.get_padding(pos.into());
+            let width = get_text_width(&text) + padding.left.size + padding.right.size;
+            widths[pos.1] = widths[pos.1].max(width);
+        }
+    }
+
     let total_width = get_table_total_width(&widths, cfg);
     (widths, total_width)
 }
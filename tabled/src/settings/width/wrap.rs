@@ -9,7 +9,7 @@ use crate::{
         config::{ColoredConfig, Entity},
         dimension::CompleteDimensionVecRecords,
         records::{EmptyRecords, ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
-        util::string::{get_char_width, get_string_width, get_text_width},
+        util::string::{get_grapheme_width, get_graphemes, get_string_width, get_text_width},
     },
     settings::{
         measurement::Measurement,
@@ -30,6 +30,9 @@ use crate::util::string::split_at_width;
 /// Be aware that it doesn't consider padding.
 /// So if you want to set a exact width you might need to use [`Padding`] to set it to 0.
 ///
+/// A text is wrapped in its logical order,
+/// so in case a [`Direction`] is set each line is reordered separately and bidirectional runs are kept.
+///
 /// ## Example
 ///
 /// ```
@@ -40,6 +43,7 @@ use crate::util::string::split_at_width;
 /// ```
 ///
 /// [`Padding`]: crate::settings::Padding
+/// [`Direction`]: crate::settings::Direction
 #[derive(Debug, Clone)]
 pub struct Wrap<W = usize, P = PriorityNone> {
     width: W,
@@ -208,34 +212,27 @@ pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
 
 #[cfg(feature = "ansi")]
 pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
-    use crate::util::string::strip_osc;
+    use crate::util::string::Hyperlinks;
 
     if width == 0 {
         return String::new();
     }
 
-    let (text, url): (String, Option<String>) = strip_osc(text);
-    let (prefix, suffix) = build_link_prefix_suffix(url);
+    // links are removed and put back after wrapping,
+    // so they are closed at the end of each line and re-opened on the next one.
+    let (text, links) = Hyperlinks::split(text);
 
-    if keep_words {
-        split_keeping_words(&text, width, &prefix, &suffix)
+    let text = if keep_words {
+        split_keeping_words(&text, width, "", "")
     } else {
-        chunks(&text, width, &prefix, &suffix).join("\n")
-    }
-}
+        chunks(&text, width, "", "").join("\n")
+    };
 
-#[cfg(feature = "ansi")]
-fn build_link_prefix_suffix(url: Option<String>) -> (String, String) {
-    match url {
-        Some(url) => {
-            // https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
-            let osc8 = "\x1b]8;;";
-            let st = "\x1b\\";
-
-            (format!("{osc8}{url}{st}"), format!("{osc8}{st}"))
-        }
-        None => ("".to_string(), "".to_string()),
+    if links.is_empty() {
+        return text;
     }
+
+    links.restore(&text)
 }
 
 #[cfg(not(feature = "ansi"))]
@@ -249,15 +246,15 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
     let mut buf = String::with_capacity(width);
     let mut list = Vec::new();
     let mut i = 0;
-    for c in s.chars() {
-        let c_width = get_char_width(c);
-        if i + c_width > width {
+    for grapheme in get_graphemes(s) {
+        let g_width = get_grapheme_width(grapheme);
+        if i + g_width > width {
             let count_unknowns = width - i;
             buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
             i += count_unknowns;
         } else {
-            buf.push(c);
-            i += c_width;
+            buf.push_str(grapheme);
+            i += g_width;
         }
 
         if i == width {
@@ -452,20 +449,20 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
     buf.set_prefix(prefix);
     buf.set_suffix(suffix);
 
-    for c in stripped_text.chars() {
-        match c {
-            ' ' => {
+    for grapheme in get_graphemes(&stripped_text) {
+        match grapheme {
+            " " => {
                 parsing::handle_word(&mut buf, &mut blocks, word_chars, word_width, 1);
                 word_chars = 0;
                 word_width = 0;
             }
-            '\n' => {
+            "\n" => {
                 parsing::handle_word(&mut buf, &mut blocks, word_chars, word_width, 1);
                 word_chars = 0;
                 word_width = 0;
             }
             _ => {
-                word_width += get_char_width(c);
+                word_width += get_grapheme_width(grapheme);
                 word_chars += 1;
             }
         }
@@ -481,7 +478,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
 
 #[cfg(feature = "ansi")]
 mod parsing {
-    use super::get_char_width;
+    use super::{get_grapheme_width, get_graphemes};
     use ansi_str::{AnsiBlock, AnsiBlockIter, Style};
     use std::fmt::Write;
 
@@ -568,7 +565,7 @@ mod parsing {
         }
 
         pub(super) fn fill(&mut self, c: char) -> usize {
-            debug_assert_eq!(get_char_width(c), 1);
+            debug_assert_eq!(get_grapheme_width(c.encode_utf8(&mut [0; 4])), 1);
 
             let rest_width = self.available_width();
             for _ in 0..rest_width {
@@ -616,15 +613,15 @@ mod parsing {
         pub(super) fn read_chars(&mut self, block: &RelativeBlock<'_>, n: usize) -> (usize, usize) {
             let mut count_chars = 0;
             let mut count_bytes = 0;
-            for c in block.get_text().chars() {
+            for grapheme in get_graphemes(block.get_text()) {
                 if count_chars == n {
                     break;
                 }
 
                 count_chars += 1;
-                count_bytes += c.len_utf8();
+                count_bytes += grapheme.len();
 
-                let cwidth = get_char_width(c);
+                let cwidth = get_grapheme_width(grapheme);
 
                 let available_space = self.width - self.width_last;
                 if available_space == 0 {
@@ -649,7 +646,7 @@ mod parsing {
                     let _ = self.fill(REPLACEMENT);
                     self.width_last = self.width;
                 } else {
-                    self.buf.push(c);
+                    self.buf.push_str(grapheme);
                     self.width_last += cwidth;
                 }
             }
@@ -664,18 +661,18 @@ mod parsing {
         ) -> (usize, usize) {
             let mut count_chars = 0;
             let mut count_bytes = 0;
-            for c in block.get_text().chars() {
+            for grapheme in get_graphemes(block.get_text()) {
                 if count_chars == n {
                     break;
                 }
 
                 count_chars += 1;
-                count_bytes += c.len_utf8();
+                count_bytes += grapheme.len();
 
-                let cwidth = get_char_width(c);
+                let cwidth = get_grapheme_width(grapheme);
                 self.width_last += cwidth;
 
-                self.buf.push(c);
+                self.buf.push_str(grapheme);
             }
 
             debug_assert!(self.width_last <= self.width);
@@ -1118,6 +1115,7 @@ mod tests {
     #[test]
     fn split_by_line_keeping_words_color_3_test() {
         let split = |text, width| split_keeping_words(text, width, "", "");
+        #[cfg(not(feature = "grapheme"))]
         assert_eq!(
             split(
                 "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
@@ -1125,6 +1123,14 @@ mod tests {
             ),
             "\u{1b}[37m🚵�\u{1b}[39m\n\u{1b}[37m🚵�\u{1b}[39m\n\u{1b}[37m🚵�\u{1b}[39m\n\u{1b}[37m🚵�\u{1b}[39m\n\u{1b}[37m🚵�\u{1b}[39m\n\u{1b}[37m🚵�\u{1b}[39m\n\u{1b}[37m🚵�\u{1b}[39m\n\u{1b}[37m🚵�\u{1b}[39m\n\u{1b}[37m🚵�\u{1b}[39m\n\u{1b}[37m🚵�\u{1b}[39m",
         );
+        #[cfg(feature = "grapheme")]
+        assert_eq!(
+            split(
+                "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
+                4,
+            ),
+            "\u{1b}[37m🚵🏻🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻🚵🏻\u{1b}[39m\n\u{1b}[37m🚵🏻🚵🏻\u{1b}[39m",
+        );
         assert_eq!(
             split("\u{1b}[37mthis is a long sentence\u{1b}[0m", 7),
             "\u{1b}[37mthis is\u{1b}[39m\n\u{1b}[37m a long\u{1b}[39m\n\u{1b}[37m senten\u{1b}[39m\n\u{1b}[37mce\u{1b}[39m     "
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::{settings::ColumnSettings, Table};
+
 /// Tabled a trait responsible for providing a header fields and a row fields.
 ///
 /// It's urgent that `header` len is equal to `fields` len.
@@ -18,6 +20,67 @@ pub trait Tabled {
     fn fields(&self) -> Vec<Cow<'_, str>>;
     /// Headers must return a list of column names.
     fn headers() -> Vec<Cow<'static, str>>;
+
+    /// Column settings returns a list of settings of each column,
+    /// like an alignment or a color, which are applied by [`Table::new`].
+    ///
+    /// By default there's no settings.
+    ///
+    /// [`Table::new`]: crate::Table::new
+    fn column_settings() -> Vec<ColumnSettings> {
+        Vec::new()
+    }
+
+    /// Column groups returns a name of a group of each column, if any.
+    ///
+    /// In case there's at least one group [`Table::new`] renders a two-row header,
+    /// where a group name spans all its consecutive columns
+    /// and the name of a column which is not grouped spans both rows.
+    ///
+    /// By default there's no groups.
+    ///
+    /// [`Table::new`]: crate::Table::new
+    fn column_groups() -> Vec<Option<Cow<'static, str>>> {
+        Vec::new()
+    }
+
+    /// Header keys returns a key of each column, if any,
+    /// which [`Table::new_with_headers`] uses to get a column name from a [`HeaderProvider`].
+    ///
+    /// A column without a key or with a key which is not known to a provider
+    /// uses a name from [`Tabled::headers`].
+    ///
+    /// By default there's no keys.
+    ///
+    /// [`Table::new_with_headers`]: crate::Table::new_with_headers
+    /// [`HeaderProvider`]: crate::HeaderProvider
+    fn header_keys() -> Vec<Option<Cow<'static, str>>> {
+        Vec::new()
+    }
+
+    /// Whether [`Tabled::present`] is overridden.
+    ///
+    /// [`Table::new`] keeps given rows to pass them to [`Tabled::present`] only if it's `true`,
+    /// otherwise [`Tabled::present`] is not called.
+    ///
+    /// By default it's `false`.
+    ///
+    /// [`Table::new`]: crate::Table::new
+    const HAS_PRESENTATION: bool = false;
+
+    /// Present changes a whole table built by [`Table::new`] out of given rows,
+    /// like setting a style or adding a header.
+    ///
+    /// It's called only if [`Tabled::HAS_PRESENTATION`] is `true`.
+    ///
+    /// By default it does nothing.
+    /// `#[derive(Tabled)]` uses it to apply a [`TabledPresentation`].
+    ///
+    /// [`Table::new`]: crate::Table::new
+    /// [`TabledPresentation`]: crate::TabledPresentation
+    fn present(table: &mut Table, rows: &[&Self]) {
+        let _ = (table, rows);
+    }
 }
 
 impl<T> Tabled for &T
@@ -25,6 +88,7 @@ where
     T: Tabled,
 {
     const LENGTH: usize = T::LENGTH;
+    const HAS_PRESENTATION: bool = T::HAS_PRESENTATION;
 
     fn fields(&self) -> Vec<Cow<'_, str>> {
         T::fields(self)
@@ -32,6 +96,19 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn column_settings() -> Vec<ColumnSettings> {
+        T::column_settings()
+    }
+    fn column_groups() -> Vec<Option<Cow<'static, str>>> {
+        T::column_groups()
+    }
+    fn header_keys() -> Vec<Option<Cow<'static, str>>> {
+        T::header_keys()
+    }
+    fn present(table: &mut Table, rows: &[&Self]) {
+        let rows = rows.iter().map(|row| &***row).collect::<Vec<_>>();
+        T::present(table, &rows)
+    }
 }
 
 impl<T> Tabled for Box<T>
@@ -39,6 +116,7 @@ where
     T: Tabled,
 {
     const LENGTH: usize = T::LENGTH;
+    const HAS_PRESENTATION: bool = T::HAS_PRESENTATION;
 
     fn fields(&self) -> Vec<Cow<'_, str>> {
         T::fields(self)
@@ -46,6 +124,19 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn column_settings() -> Vec<ColumnSettings> {
+        T::column_settings()
+    }
+    fn column_groups() -> Vec<Option<Cow<'static, str>>> {
+        T::column_groups()
+    }
+    fn header_keys() -> Vec<Option<Cow<'static, str>>> {
+        T::header_keys()
+    }
+    fn present(table: &mut Table, rows: &[&Self]) {
+        let rows = rows.iter().map(|row| &***row).collect::<Vec<_>>();
+        T::present(table, &rows)
+    }
 }
 
 macro_rules! tuple_table {
@@ -66,6 +157,36 @@ macro_rules! tuple_table {
                 $(fields.append(&mut $name::headers());)+
                 fields
             }
+
+            fn column_settings() -> Vec<ColumnSettings> {
+                let mut settings = Vec::with_capacity(Self::LENGTH);
+                $(
+                    let mut list = $name::column_settings();
+                    list.resize($name::LENGTH, ColumnSettings::new());
+                    settings.append(&mut list);
+                )+
+                settings
+            }
+
+            fn column_groups() -> Vec<Option<Cow<'static, str>>> {
+                let mut groups = Vec::with_capacity(Self::LENGTH);
+                $(
+                    let mut list = $name::column_groups();
+                    list.resize($name::LENGTH, None);
+                    groups.append(&mut list);
+                )+
+                groups
+            }
+
+            fn header_keys() -> Vec<Option<Cow<'static, str>>> {
+                let mut keys = Vec::with_capacity(Self::LENGTH);
+                $(
+                    let mut list = $name::header_keys();
+                    list.resize($name::LENGTH, None);
+                    keys.append(&mut list);
+                )+
+                keys
+            }
         }
     };
 }
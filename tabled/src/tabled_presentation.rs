@@ -0,0 +1,99 @@
+use std::borrow::Cow;
+
+use crate::{
+    settings::{themes::Theme, Panel},
+    Table, Tabled,
+};
+
+/// TabledPresentation declares how a whole table of a type must look like,
+/// like its style, a header or a footer.
+///
+/// It's applied by [`Table::new`] through [`Tabled::present`],
+/// which `#[derive(Tabled)]` implements by
+/// `#[tabled(style = "rounded", header = "Users", footer_fn = "Self::totals")]` attributes.
+///
+/// In case of a manual implementation [`Tabled::present`] needs to call [`TabledPresentation::apply`],
+/// and [`Tabled::HAS_PRESENTATION`] needs to be set to `true`.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+/// use tabled::{settings::{themes::Theme, Style}, Table, Tabled, TabledPresentation};
+///
+/// struct Point(i32, i32);
+///
+/// impl Tabled for Point {
+///     const LENGTH: usize = 2;
+///     const HAS_PRESENTATION: bool = true;
+///
+///     fn fields(&self) -> Vec<Cow<'_, str>> {
+///         vec![self.0.to_string().into(), self.1.to_string().into()]
+///     }
+///
+///     fn headers() -> Vec<Cow<'static, str>> {
+///         vec!["x".into(), "y".into()]
+///     }
+///
+///     fn present(table: &mut Table, rows: &[&Self]) {
+///         <Self as TabledPresentation>::apply(table, rows)
+///     }
+/// }
+///
+/// impl TabledPresentation for Point {
+///     fn style() -> Option<Theme> {
+///         Some(Theme::from_style(Style::psql()))
+///     }
+///
+///     fn footer(rows: &[&Self]) -> Option<String> {
+///         Some(format!("{} points", rows.len()))
+///     }
+/// }
+///
+/// let table = Table::new([Point(1, 2), Point(3, 4)]).to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " x   | y  \n",
+///         "-----+----\n",
+///         " 1   | 2  \n",
+///         " 3   | 4  \n",
+///         " 2 points ",
+///     ),
+/// );
+/// ```
+///
+/// [`Table::new`]: crate::Table::new
+pub trait TabledPresentation: Tabled {
+    /// Returns a style of a table.
+    fn style() -> Option<Theme> {
+        None
+    }
+
+    /// Returns a text which is put above column names.
+    fn header() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// Returns a text which is put below all rows.
+    fn footer(rows: &[&Self]) -> Option<String> {
+        let _ = rows;
+        None
+    }
+
+    /// Applies the presentation to a table.
+    fn apply(table: &mut Table, rows: &[&Self]) {
+        if let Some(theme) = Self::style() {
+            let _ = table.with(theme);
+        }
+
+        if let Some(header) = Self::header() {
+            let _ = table.with(Panel::header(header));
+        }
+
+        if let Some(footer) = Self::footer(rows) {
+            let _ = table.with(Panel::footer(footer));
+        }
+    }
+}
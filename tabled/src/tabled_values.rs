@@ -0,0 +1,263 @@
+use std::{borrow::Cow, convert::TryFrom, fmt};
+
+use crate::Tabled;
+
+/// A typed value of a cell.
+///
+/// Unlike [`Tabled::fields`] it keeps an original type of a value,
+/// so it can be used for sorting, exporting and alike.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TabledValue<'a> {
+    /// An absent value, like [`None`].
+    Null,
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value.
+    Int(i128),
+    /// A floating point value.
+    Float(f64),
+    /// A text value.
+    String(Cow<'a, str>),
+    /// A date or a time represented as a text.
+    DateTime(Cow<'a, str>),
+}
+
+impl TabledValue<'_> {
+    /// Checks whether the value is [`TabledValue::Null`].
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Returns a number in case the value is [`TabledValue::Int`] or [`TabledValue::Float`].
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Int(value) => Some(*value as f64),
+            Self::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TabledValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => Ok(()),
+            Self::Bool(value) => value.fmt(f),
+            Self::Int(value) => value.fmt(f),
+            Self::Float(value) => value.fmt(f),
+            Self::String(value) | Self::DateTime(value) => value.fmt(f),
+        }
+    }
+}
+
+/// A conversion of a field into a [`TabledValue`].
+///
+/// It's used by `#[derive(TabledValues)]` for each field,
+/// so it needs to be implemented for a custom field type
+/// (or the field must use `display_with` or `format`).
+pub trait ToTabledValue {
+    /// Returns a typed value.
+    fn to_tabled_value(&self) -> TabledValue<'_>;
+}
+
+impl<T> ToTabledValue for &T
+where
+    T: ToTabledValue + ?Sized,
+{
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        T::to_tabled_value(self)
+    }
+}
+
+impl<T> ToTabledValue for Box<T>
+where
+    T: ToTabledValue + ?Sized,
+{
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        T::to_tabled_value(self)
+    }
+}
+
+impl<T> ToTabledValue for Option<T>
+where
+    T: ToTabledValue,
+{
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        match self {
+            Some(value) => value.to_tabled_value(),
+            None => TabledValue::Null,
+        }
+    }
+}
+
+impl ToTabledValue for str {
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        TabledValue::String(Cow::Borrowed(self))
+    }
+}
+
+impl ToTabledValue for String {
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        TabledValue::String(Cow::Borrowed(self))
+    }
+}
+
+impl ToTabledValue for Cow<'_, str> {
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        TabledValue::String(Cow::Borrowed(self))
+    }
+}
+
+impl ToTabledValue for char {
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        TabledValue::String(Cow::Owned(self.to_string()))
+    }
+}
+
+impl ToTabledValue for bool {
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        TabledValue::Bool(*self)
+    }
+}
+
+impl ToTabledValue for u128 {
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        match i128::try_from(*self) {
+            Ok(value) => TabledValue::Int(value),
+            Err(_) => TabledValue::String(Cow::Owned(self.to_string())),
+        }
+    }
+}
+
+macro_rules! int_value {
+    ( $($t:ty)+ ) => {
+        $(
+            impl ToTabledValue for $t {
+                fn to_tabled_value(&self) -> TabledValue<'_> {
+                    TabledValue::Int(*self as i128)
+                }
+            }
+        )+
+    };
+}
+
+int_value! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+
+impl ToTabledValue for i128 {
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        TabledValue::Int(*self)
+    }
+}
+
+impl ToTabledValue for f32 {
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        TabledValue::Float(f64::from(*self))
+    }
+}
+
+impl ToTabledValue for f64 {
+    fn to_tabled_value(&self) -> TabledValue<'_> {
+        TabledValue::Float(*self)
+    }
+}
+
+/// TabledValues is a typed companion of [`Tabled`].
+///
+/// It returns the same cells as [`Tabled::fields`]
+/// but keeps their original types.
+///
+/// ```text
+/// self.values().len() == self.fields().len()
+/// ```
+pub trait TabledValues: Tabled {
+    /// Values method must return a list of cells in the same order as [`Tabled::fields`].
+    fn values(&self) -> Vec<TabledValue<'_>>;
+}
+
+impl<T> TabledValues for &T
+where
+    T: TabledValues,
+{
+    fn values(&self) -> Vec<TabledValue<'_>> {
+        T::values(self)
+    }
+}
+
+impl<T> TabledValues for Box<T>
+where
+    T: TabledValues,
+{
+    fn values(&self) -> Vec<TabledValue<'_>> {
+        T::values(self)
+    }
+}
+
+macro_rules! tuple_values {
+    ( $($name:ident)+ ) => {
+        impl<$($name: TabledValues),+> TabledValues for ($($name,)+){
+            fn values(&self) -> Vec<TabledValue<'_>> {
+                #![allow(non_snake_case)]
+                let ($($name,)+) = self;
+                let mut values = Vec::with_capacity(Self::LENGTH);
+                $(values.append(&mut $name.values());)+
+                values
+            }
+        }
+    };
+}
+
+tuple_values! { A }
+tuple_values! { A B }
+tuple_values! { A B C }
+tuple_values! { A B C D }
+tuple_values! { A B C D E }
+tuple_values! { A B C D E F }
+
+macro_rules! default_values {
+    ( $($t:ty)+ ) => {
+        $(
+            impl TabledValues for $t {
+                fn values(&self) -> Vec<TabledValue<'_>> {
+                    vec![self.to_tabled_value()]
+                }
+            }
+        )+
+    };
+}
+
+default_values! { &str str String char bool }
+default_values! { isize usize u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 f32 f64 }
+
+impl<T, const N: usize> TabledValues for [T; N]
+where
+    T: ToTabledValue + fmt::Display,
+{
+    fn values(&self) -> Vec<TabledValue<'_>> {
+        self.iter().map(ToTabledValue::to_tabled_value).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_test() {
+        assert_eq!(1u8.values(), [TabledValue::Int(1)]);
+        assert_eq!(
+            u128::MAX.values(),
+            [TabledValue::String(u128::MAX.to_string().into())]
+        );
+        assert_eq!(
+            ("a", 1.5, true).values(),
+            [
+                TabledValue::String("a".into()),
+                TabledValue::Float(1.5),
+                TabledValue::Bool(true),
+            ]
+        );
+        assert_eq!(None::<i32>.to_tabled_value(), TabledValue::Null);
+        assert_eq!(TabledValue::Null.to_string(), "");
+        assert_eq!(TabledValue::Float(0.5).to_string(), "0.5");
+    }
+}
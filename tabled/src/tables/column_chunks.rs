@@ -0,0 +1,175 @@
+//! This module contains a [`ColumnChunks`] iterator which splits a wide [`Table`] by columns.
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        config::ColoredConfig,
+        dimension::CompleteDimensionVecRecords,
+        records::vec_records::{Text, VecRecords},
+    },
+    settings::{filter::remove_columns, TableOption},
+    Table,
+};
+
+/// An iterator over rendered parts of a [`Table`] which is too wide,
+/// each of which is not wider than a given width.
+///
+/// Each part contains the key columns (the first column by default)
+/// together with as many of the rest columns as fit.
+/// Columns keep their order in a part, so a key column stays in its place among the rest.
+/// See [`ColumnChunks::keys`] to change the key columns.
+///
+/// The split points are chosen by the rendered widths of the columns,
+/// and all parts use the same column widths as the whole table.
+/// If even a single column doesn't fit, a part will contain it anyway.
+///
+/// It's created by [`Table::split_columns`].
+#[derive(Debug, Clone)]
+pub struct ColumnChunks<'a> {
+    table: &'a Table,
+    width: usize,
+    keys: Vec<usize>,
+    state: Option<State>,
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    widths: Vec<usize>,
+    keys: Vec<bool>,
+    next_column: usize,
+    is_first_chunk: bool,
+}
+
+impl<'a> ColumnChunks<'a> {
+    pub(crate) fn new(table: &'a Table, width: usize) -> Self {
+        Self {
+            table,
+            width,
+            keys: vec![0],
+            state: None,
+        }
+    }
+
+    /// Sets the key columns which are repeated in each part.
+    ///
+    /// By default it's the first column.
+    pub fn keys<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.keys = columns.into_iter().collect();
+        self.state = None;
+        self
+    }
+
+    fn init_state(&self) -> State {
+        let count_columns = self.table.count_columns();
+
+        let (widths, _) = self.table.get_dimension().into_inner();
+        let widths = widths.unwrap_or_default();
+
+        let mut keys = vec![false; count_columns];
+        for &col in &self.keys {
+            if col < count_columns {
+                keys[col] = true;
+            }
+        }
+
+        let next_column = next_column(&keys, 0);
+
+        State {
+            widths,
+            keys,
+            next_column,
+            is_first_chunk: true,
+        }
+    }
+
+    fn chunk_width(&self, state: &State, columns: &[bool]) -> usize {
+        let cfg = self.table.get_config();
+        let margin = cfg.get_margin();
+
+        let count_columns = columns.iter().filter(|&&keep| keep).count();
+        let content = (0..columns.len())
+            .filter(|&col| columns[col])
+            .map(|col| state.widths[col])
+            .sum::<usize>();
+
+        content + cfg.count_vertical(count_columns) + margin.left.size + margin.right.size
+    }
+
+    fn take_chunk(&self, state: &State) -> (Vec<bool>, usize) {
+        let count_columns = state.keys.len();
+
+        let mut columns = state.keys.clone();
+        let mut next = state.next_column;
+        let mut is_empty = true;
+        while next < count_columns {
+            columns[next] = true;
+
+            if !is_empty && self.chunk_width(state, &columns) > self.width {
+                columns[next] = false;
+                break;
+            }
+
+            is_empty = false;
+            next = next_column(&state.keys, next + 1);
+        }
+
+        (columns, next)
+    }
+}
+
+impl Iterator for ColumnChunks<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut state = match self.state.take() {
+            Some(state) => state,
+            None => self.init_state(),
+        };
+
+        let count_columns = state.keys.len();
+        let is_done = state.next_column >= count_columns;
+        let has_keys = state.keys.iter().any(|&is_key| is_key);
+        if is_done && !(state.is_first_chunk && has_keys) {
+            self.state = Some(state);
+            return None;
+        }
+
+        let (columns, next_column) = self.take_chunk(&state);
+
+        let widths = (0..count_columns)
+            .filter(|&col| columns[col])
+            .map(|col| state.widths[col])
+            .collect::<Vec<_>>();
+
+        let mut chunk = self.table.clone();
+        let _ = chunk.with(KeepColumns(&columns));
+
+        let mut dims = CompleteDimensionVecRecords::default();
+        dims.set_widths(widths);
+        chunk.set_dimension(dims);
+
+        state.next_column = next_column;
+        state.is_first_chunk = false;
+        self.state = Some(state);
+
+        Some(chunk.to_string())
+    }
+}
+
+fn next_column(keys: &[bool], start: usize) -> usize {
+    (start..keys.len())
+        .find(|&col| !keys[col])
+        .unwrap_or(keys.len())
+}
+
+struct KeepColumns<'a>(&'a [bool]);
+
+impl<D> TableOption<VecRecords<Text<String>>, ColoredConfig, D> for KeepColumns<'_> {
+    fn change(self, records: &mut VecRecords<Text<String>>, cfg: &mut ColoredConfig, _: &mut D) {
+        remove_columns(records, cfg, self.0);
+    }
+}
@@ -0,0 +1,151 @@
+//! This module contains a [`Csv`] writer which exports a [`Table`] as CSV or TSV.
+//!
+//! [`Table`]: crate::Table
+
+use std::{borrow::Cow, fmt, io};
+
+use crate::{
+    grid::records::{PeekableRecords, Records},
+    settings::sort::strip,
+    Table,
+};
+
+/// A writer of a [`Table`] content as CSV (comma separated values).
+///
+/// It exports the records of the table as they are,
+/// so all settings which change the content
+/// (like [`Disable`], [`Extract`] or [`Format::content`]) are taken into account,
+/// while the ones which affect only the rendering (like [`Style`] or [`Padding`]) are ignored.
+///
+/// A cell is quoted in case it contains a delimiter, a quote or a new line.
+/// A cell which is covered by a span is exported as an empty one.
+///
+/// Each record is terminated by a new line.
+///
+/// It's created by [`Table::csv`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::Table;
+///
+/// let data = [("Tom", "Hello, World"), ("Ann", "Say \"Hi\"")];
+/// let table = Table::new(data);
+///
+/// assert_eq!(
+///     table.csv().delimiter(';').to_string(),
+///     "&str;&str\nTom;Hello, World\nAnn;\"Say \"\"Hi\"\"\"\n",
+/// );
+/// ```
+///
+/// [`Disable`]: crate::settings::Disable
+/// [`Extract`]: crate::settings::Extract
+/// [`Format::content`]: crate::settings::Format::content
+/// [`Style`]: crate::settings::Style
+/// [`Padding`]: crate::settings::Padding
+#[derive(Debug, Clone)]
+pub struct Csv<'a> {
+    table: &'a Table,
+    delimiter: char,
+    strip_ansi: bool,
+}
+
+impl<'a> Csv<'a> {
+    pub(crate) fn new(table: &'a Table, delimiter: char) -> Self {
+        Self {
+            table,
+            delimiter,
+            strip_ansi: false,
+        }
+    }
+
+    /// Sets a delimiter of cells.
+    ///
+    /// By default it's `,`.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether ANSI sequences must be removed from cells.
+    ///
+    /// It's off by default.
+    #[cfg(feature = "ansi")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ansi")))]
+    pub fn strip_ansi(mut self, on: bool) -> Self {
+        self.strip_ansi = on;
+        self
+    }
+
+    /// Writes the CSV into a given writer.
+    pub fn write<W>(&self, mut writer: W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let mut buf = String::new();
+        for row in 0..self.table.count_rows() {
+            buf.clear();
+            self.build_record(row, &mut buf);
+            writer.write_all(buf.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    fn build_record(&self, row: usize, buf: &mut String) {
+        let records = self.table.get_records();
+        let cfg = self.table.get_config();
+
+        for col in 0..records.count_columns() {
+            if col > 0 {
+                buf.push(self.delimiter);
+            }
+
+            let pos = (row, col);
+            if !cfg.is_cell_visible(pos) {
+                continue;
+            }
+
+            let text = records.get_text(pos);
+            let text = match self.strip_ansi {
+                true => strip(text),
+                false => Cow::Borrowed(text),
+            };
+
+            push_field(buf, &text, self.delimiter);
+        }
+
+        buf.push('\n');
+    }
+}
+
+impl fmt::Display for Csv<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = String::new();
+        for row in 0..self.table.count_rows() {
+            buf.clear();
+            self.build_record(row, &mut buf);
+            f.write_str(&buf)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn push_field(buf: &mut String, text: &str, delimiter: char) {
+    let is_quoted = text.contains([delimiter, '"', '\n', '\r']);
+    if !is_quoted {
+        buf.push_str(text);
+        return;
+    }
+
+    buf.push('"');
+    for c in text.chars() {
+        if c == '"' {
+            buf.push('"');
+        }
+
+        buf.push(c);
+    }
+    buf.push('"');
+}
@@ -24,6 +24,8 @@
 //! );
 //! ```
 //!
+//! It can be also rendered row by row as records arrive by [`IterTable::stream`].
+//!
 //! [`Table`]: crate::Table
 
 use std::{fmt, io};
@@ -31,15 +33,19 @@ use std::{fmt, io};
 use crate::{
     grid::{
         colors::NoColors,
-        config::{AlignmentHorizontal, CompactConfig, Indent, Sides, SpannedConfig},
+        config::{AlignmentHorizontal, Borders, CompactConfig, Indent, Sides, SpannedConfig},
         dimension::{CompactGridDimension, Dimension, DimensionValue, StaticDimension},
         records::{
             into_records::{BufRecords, LimitColumns, LimitRows, TruncateContent},
             IntoRecords, IterRecords,
         },
+        util::string::{count_lines, get_text_width},
         Grid,
     },
-    settings::{Style, TableOption},
+    settings::{
+        width::{Truncate, Wrap},
+        Style, TableOption,
+    },
 };
 
 use super::util::utf8_writer::UTF8Writer;
@@ -63,6 +69,7 @@ struct Settings {
     count_rows: Option<usize>,
     width: Option<usize>,
     height: Option<usize>,
+    wrap: bool,
 }
 
 impl<I> IterTable<I> {
@@ -80,6 +87,7 @@ impl<I> IterTable<I> {
                 count_rows: None,
                 height: None,
                 width: None,
+                wrap: false,
             },
         }
     }
@@ -127,6 +135,14 @@ impl<I> IterTable<I> {
         self
     }
 
+    /// Wrap a content of a row which doesn't fit a column width instead of truncating it.
+    ///
+    /// It's taken into account only by [`IterTable::stream`].
+    pub fn wrap(mut self, on: bool) -> Self {
+        self.table.wrap = on;
+        self
+    }
+
     /// Build a string.
     ///
     /// We can't implement [`std::string::ToString`] cause it does takes `&self` reference.
@@ -164,6 +180,253 @@ impl<I> IterTable<I> {
     {
         build_grid(writer, self.records, self.cfg, self.table)
     }
+
+    /// Renders the table into a [`io::Write`]r row by row,
+    /// so a row is written (and the writer is flushed) as soon as an iterator yields it.
+    ///
+    /// Column widths are taken from [`IterTable::width`],
+    /// or otherwise sniffed from the first rows (see [`IterTable::sniff`]).
+    /// Later rows which don't fit are truncated, or wrapped in case of [`IterTable::wrap`].
+    ///
+    /// It returns an [`IterTableStream`] which can be given more rows,
+    /// the bottom border is written by [`IterTableStream::finish`] or on drop.
+    /// Each line is terminated by a new line.
+    ///
+    /// ```
+    /// use tabled::tables::IterTable;
+    ///
+    /// let mut buf = Vec::new();
+    ///
+    /// let mut stream = IterTable::new([["id", "name"], ["1", "Tom"]])
+    ///     .stream(&mut buf)
+    ///     .unwrap();
+    /// stream.push_row(["2", "Anna"]).unwrap();
+    /// stream.finish().unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(buf).unwrap(),
+    ///     concat!(
+    ///         "+----+------+\n",
+    ///         "| id | name |\n",
+    ///         "+----+------+\n",
+    ///         "| 1  | Tom  |\n",
+    ///         "+----+------+\n",
+    ///         "| 2  | Anna |\n",
+    ///         "+----+------+\n",
+    ///     ),
+    /// );
+    /// ```
+    pub fn stream<W>(self, writer: W) -> io::Result<IterTableStream<W>>
+    where
+        W: io::Write,
+        I: IntoRecords,
+        I::Cell: AsRef<str>,
+    {
+        let opts = self.table;
+        let sniff = match (opts.width, opts.count_columns) {
+            (Some(_), Some(_)) => 0,
+            _ => opts.sniff,
+        };
+
+        let records = BufRecords::new(self.records, sniff);
+        let count_columns = get_count_columns(&opts, records.as_slice());
+
+        let widths = match opts.width {
+            Some(width) => vec![width; count_columns],
+            None => {
+                let records = LimitColumns::new(records.as_slice(), count_columns);
+                let records = IterRecords::new(records, count_columns, None);
+                let (widths, _) = CompactGridDimension::dimension(records, &self.cfg);
+
+                let padding = self.cfg.get_padding();
+                let pad = padding.left.size + padding.right.size;
+                widths.into_iter().map(|w| w.saturating_sub(pad)).collect()
+            }
+        };
+
+        let mut stream = IterTableStream {
+            writer,
+            cfg: self.cfg,
+            widths,
+            height: opts.height,
+            wrap: opts.wrap,
+            limit: opts.count_rows,
+            count_rows: 0,
+            is_finished: false,
+        };
+
+        for row in records.iter_rows() {
+            stream.push_row(row)?;
+        }
+
+        Ok(stream)
+    }
+}
+
+/// A table which is being written row by row by [`IterTable::stream`].
+///
+/// The bottom border is written by [`IterTableStream::finish`],
+/// or when the stream is dropped (ignoring errors).
+#[derive(Debug)]
+pub struct IterTableStream<W>
+where
+    W: io::Write,
+{
+    writer: W,
+    cfg: CompactConfig,
+    widths: Vec<usize>,
+    height: Option<usize>,
+    wrap: bool,
+    limit: Option<usize>,
+    count_rows: usize,
+    is_finished: bool,
+}
+
+impl<W> IterTableStream<W>
+where
+    W: io::Write,
+{
+    /// Writes a row and flushes the writer.
+    ///
+    /// Cells which go beyond a number of columns are ignored,
+    /// and missing ones are rendered empty.
+    /// A row which exceeds [`IterTable::rows`] limit is ignored.
+    pub fn push_row<R>(&mut self, row: R) -> io::Result<()>
+    where
+        R: IntoIterator,
+        R::Item: AsRef<str>,
+    {
+        if matches!(self.limit, Some(limit) if self.count_rows >= limit) {
+            return Ok(());
+        }
+
+        let count_columns = self.widths.len();
+        let mut cells = row
+            .into_iter()
+            .take(count_columns)
+            .zip(&self.widths)
+            .map(|(text, &width)| fit_text(text.as_ref(), width, self.wrap))
+            .collect::<Vec<_>>();
+        cells.resize(count_columns, String::new());
+
+        let height = match self.height {
+            Some(height) => height,
+            None => cells
+                .iter()
+                .map(|text| count_lines(text))
+                .max()
+                .unwrap_or(1),
+        };
+
+        let mut borders = *self.cfg.get_borders();
+        let mut margin = *self.cfg.get_margin();
+        if self.count_rows > 0 {
+            borders.top = borders.horizontal;
+            borders.top_left = borders.left_intersection;
+            borders.top_right = borders.right_intersection;
+            borders.top_intersection = borders.intersection;
+            margin.top = Indent::default();
+        }
+        borders.bottom = None;
+        borders.bottom_left = None;
+        borders.bottom_right = None;
+        borders.bottom_intersection = None;
+        margin.bottom = Indent::default();
+
+        let cfg = self.cfg.set_borders(borders).set_margin(margin);
+
+        self.write_grid(vec![cells], cfg, height)?;
+        self.count_rows += 1;
+
+        Ok(())
+    }
+
+    /// Writes the bottom border and flushes the writer.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.close()
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        if self.is_finished {
+            return Ok(());
+        }
+
+        self.is_finished = true;
+
+        let borders = *self.cfg.get_borders();
+        let mut margin = *self.cfg.get_margin();
+        margin.top = Indent::default();
+
+        let cfg = self
+            .cfg
+            .set_borders(Borders {
+                top: borders.bottom,
+                top_left: borders.bottom_left,
+                top_right: borders.bottom_right,
+                top_intersection: borders.bottom_intersection,
+                vertical: borders.vertical,
+                left: borders.left,
+                right: borders.right,
+                ..Borders::empty()
+            })
+            .set_margin(margin);
+
+        let cells = vec![String::new(); self.widths.len()];
+        self.write_grid(vec![cells], cfg, 0)
+    }
+
+    fn write_grid(
+        &mut self,
+        rows: Vec<Vec<String>>,
+        cfg: CompactConfig,
+        height: usize,
+    ) -> io::Result<()> {
+        let padding = cfg.get_padding();
+        let pad = padding.left.size + padding.right.size;
+        let padv = padding.top.size + padding.bottom.size;
+        let padv = if height == 0 { 0 } else { padv };
+
+        let widths = self.widths.iter().map(|w| w + pad).collect();
+        let dims = StaticDimension::new(
+            DimensionValue::List(widths),
+            DimensionValue::Exact(height + padv),
+        );
+
+        let count_columns = self.widths.len();
+        let records = IterRecords::new(rows, count_columns, None);
+
+        let mut buf = String::new();
+        Grid::new(records, dims, SpannedConfig::from(cfg), NoColors)
+            .build(&mut buf)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+
+        if !buf.is_empty() {
+            buf.push('\n');
+            self.writer.write_all(buf.as_bytes())?;
+        }
+
+        self.writer.flush()
+    }
+}
+
+impl<W> Drop for IterTableStream<W>
+where
+    W: io::Write,
+{
+    fn drop(&mut self) {
+        let _ = self.close();
+    }
+}
+
+fn fit_text(text: &str, width: usize, wrap: bool) -> String {
+    if get_text_width(text) <= width {
+        return text.to_owned();
+    }
+
+    match wrap {
+        true => Wrap::wrap(text, width, false),
+        false => Truncate::truncate(text, width).into_owned(),
+    }
 }
 
 fn build_grid<W, I>(f: W, iter: I, cfg: CompactConfig, opts: Settings) -> fmt::Result
@@ -0,0 +1,205 @@
+//! This module contains a [`LiveTable`] renderer,
+//! which redraws an [`IterTable`] in place.
+
+use std::{fmt::Write as _, io};
+
+use crate::{
+    grid::{
+        records::IntoRecords,
+        util::string::{get_grapheme_width, get_graphemes},
+    },
+    tables::IterTable,
+};
+
+/// A renderer which draws [`IterTable`]s one over another in a terminal,
+/// like a dashboard which is updated every second.
+///
+/// The first frame is written as it is.
+/// Each next frame is compared with a previous one line by line,
+/// and only changed parts of lines are rewritten using ANSI cursor movements,
+/// so there's no flickering of a full redraw.
+///
+/// - A cursor is expected to be at the beginning of a line when the first frame is drawn,
+///   and it's left on a line below a frame.
+/// - Lines are rewritten from the first to the last changed character.
+/// - A line which contains an ANSI sequence is rewritten fully.
+///
+/// # Example
+///
+/// ```
+/// use tabled::tables::{IterTable, LiveTable};
+///
+/// let mut live = LiveTable::new(Vec::new());
+///
+/// live.render(IterTable::new([["cpu", "10%"]])).unwrap();
+/// live.render(IterTable::new([["cpu", "15%"]])).unwrap();
+///
+/// let output = String::from_utf8(live.into_inner()).unwrap();
+///
+/// assert_eq!(
+///     output,
+///     concat!(
+///         "+-----+-----+\n",
+///         "| cpu | 10% |\n",
+///         "+-----+-----+\n",
+///         // move 2 lines up, to the 10th column, write "5" and move back
+///         "\u{1b}[2A\u{1b}[10G5\r\u{1b}[2B",
+///     ),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiveTable<W> {
+    writer: W,
+    lines: Vec<String>,
+    is_drawn: bool,
+}
+
+impl<W> LiveTable<W>
+where
+    W: io::Write,
+{
+    /// Creates a new [`LiveTable`] which writes into a given writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            lines: Vec::new(),
+            is_drawn: false,
+        }
+    }
+
+    /// Draws a table in place of a previous one and flushes the writer.
+    pub fn render<I>(&mut self, table: IterTable<I>) -> io::Result<()>
+    where
+        I: IntoRecords,
+        I::Cell: AsRef<str>,
+    {
+        let frame = table.to_string();
+        self.draw(&frame)
+    }
+
+    /// Forgets a previous frame, so a next one is drawn below it.
+    pub fn reset(&mut self) {
+        self.lines.clear();
+        self.is_drawn = false;
+    }
+
+    /// Returns a reference to an underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns an underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn draw(&mut self, frame: &str) -> io::Result<()> {
+        let lines = frame.lines().map(String::from).collect::<Vec<_>>();
+
+        let mut buf = String::new();
+        if self.is_drawn {
+            build_diff(&mut buf, &self.lines, &lines);
+        } else {
+            for line in &lines {
+                buf.push_str(line);
+                buf.push('\n');
+            }
+        }
+
+        self.writer.write_all(buf.as_bytes())?;
+        self.writer.flush()?;
+
+        self.lines = lines;
+        self.is_drawn = true;
+
+        Ok(())
+    }
+}
+
+/// Builds a sequence which turns a previous frame into a next one,
+/// given a cursor is on a line below the previous frame.
+fn build_diff(buf: &mut String, prev: &[String], next: &[String]) {
+    let mut row = prev.len();
+
+    for (i, (prev_line, next_line)) in prev.iter().zip(next).enumerate() {
+        if prev_line != next_line {
+            move_cursor(buf, &mut row, i);
+            build_line_diff(buf, prev_line, next_line);
+        }
+    }
+
+    if next.len() < prev.len() {
+        move_cursor(buf, &mut row, next.len());
+        buf.push_str("\r\u{1b}[J");
+        return;
+    }
+
+    move_cursor(buf, &mut row, prev.len());
+
+    for line in &next[prev.len()..] {
+        buf.push_str(line);
+        buf.push('\n');
+    }
+}
+
+fn move_cursor(buf: &mut String, row: &mut usize, target: usize) {
+    if target < *row {
+        let _ = write!(buf, "\u{1b}[{}A", *row - target);
+    } else if target > *row {
+        let _ = write!(buf, "\u{1b}[{}B", target - *row);
+    }
+
+    *row = target;
+}
+
+/// Rewrites a changed part of a line, leaving a cursor at the beginning of the line.
+fn build_line_diff(buf: &mut String, prev: &str, next: &str) {
+    if prev.contains('\u{1b}') || next.contains('\u{1b}') {
+        buf.push('\r');
+        buf.push_str(next);
+        buf.push_str("\u{1b}[K\r");
+        return;
+    }
+
+    let prev_chars = get_graphemes(prev).collect::<Vec<_>>();
+    let next_chars = get_graphemes(next).collect::<Vec<_>>();
+
+    let prefix = prev_chars
+        .iter()
+        .zip(&next_chars)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let prev_width = string_width(&prev_chars);
+    let next_width = string_width(&next_chars);
+
+    // a common suffix is kept only in case it's on the same position
+    let suffix = if prev_width == next_width {
+        prev_chars[prefix..]
+            .iter()
+            .rev()
+            .zip(next_chars[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count()
+    } else {
+        0
+    };
+
+    let column = string_width(&next_chars[..prefix]);
+    let _ = write!(buf, "\u{1b}[{}G", column + 1);
+    buf.extend(
+        next_chars[prefix..next_chars.len() - suffix]
+            .iter()
+            .copied(),
+    );
+
+    if next_width < prev_width {
+        buf.push_str("\u{1b}[K");
+    }
+
+    buf.push('\r');
+}
+
+fn string_width(graphemes: &[&str]) -> usize {
+    graphemes.iter().map(|g| get_grapheme_width(g)).sum()
+}
@@ -0,0 +1,136 @@
+//! This module contains a [`Markdown`] renderer of a [`Table`].
+//!
+//! [`Table`]: crate::Table
+
+use std::fmt;
+
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, Entity},
+        records::{ExactRecords, PeekableRecords, Records},
+        util::string::get_lines,
+    },
+    settings::sort::strip,
+    Table,
+};
+
+/// A renderer of a [`Table`] as a GitHub flavored Markdown table.
+///
+/// Unlike [`Style::markdown`] which only mimics Markdown,
+/// it produces a valid Markdown table.
+///
+/// - The first row is used as a header.
+/// - An alignment row (like `:---:`) is built from a horizontal alignment of a column.
+///   The alignment of a first row after the header is used.
+/// - `|` is escaped, and new lines are replaced by `<br>`.
+/// - Padding, margins and styles are dropped.
+/// - A cell which is covered by a span is rendered as an empty one.
+/// - ANSI sequences are removed in case `ansi` feature is on.
+///
+/// Each line is terminated by a new line.
+///
+/// It's created by [`Table::markdown`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{object::Columns, Alignment, Modify}};
+///
+/// let data = [("Tom", 27, "likes | pipes"), ("Ann", 9, "first line\nsecond line")];
+///
+/// let mut table = Table::new(data);
+/// table.with(Modify::new(Columns::single(1)).with(Alignment::right()));
+///
+/// assert_eq!(
+///     table.markdown().to_string(),
+///     concat!(
+///         "| &str | i32 | &str |\n",
+///         "| :--- | ---: | :--- |\n",
+///         "| Tom | 27 | likes \\| pipes |\n",
+///         "| Ann | 9 | first line<br>second line |\n",
+///     ),
+/// );
+/// ```
+///
+/// [`Style::markdown`]: crate::settings::Style::markdown
+#[derive(Debug, Clone)]
+pub struct Markdown<'a> {
+    table: &'a Table,
+}
+
+impl<'a> Markdown<'a> {
+    pub(crate) fn new(table: &'a Table) -> Self {
+        Self { table }
+    }
+}
+
+impl fmt::Display for Markdown<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let records = self.table.get_records();
+        let cfg = self.table.get_config();
+
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return Ok(());
+        }
+
+        let mut buf = String::new();
+        for row in 0..count_rows {
+            buf.push('|');
+            for col in 0..count_columns {
+                buf.push(' ');
+
+                let pos = (row, col);
+                if cfg.is_cell_visible(pos) {
+                    push_cell(&mut buf, records.get_text(pos));
+                }
+
+                buf.push_str(" |");
+            }
+            buf.push('\n');
+
+            if row == 0 {
+                let alignment_row = if count_rows > 1 { 1 } else { 0 };
+
+                buf.push('|');
+                for col in 0..count_columns {
+                    let entity = Entity::Cell(alignment_row, col);
+                    let marker = match cfg.get_alignment_horizontal(entity) {
+                        AlignmentHorizontal::Left => ":---",
+                        AlignmentHorizontal::Center => ":---:",
+                        AlignmentHorizontal::Right => "---:",
+                    };
+
+                    buf.push(' ');
+                    buf.push_str(marker);
+                    buf.push_str(" |");
+                }
+                buf.push('\n');
+            }
+
+            f.write_str(&buf)?;
+            buf.clear();
+        }
+
+        Ok(())
+    }
+}
+
+fn push_cell(buf: &mut String, text: &str) {
+    let text = strip(text);
+    for (i, line) in get_lines(&text).enumerate() {
+        if i > 0 {
+            buf.push_str("<br>");
+        }
+
+        let line = line.strip_suffix('\r').unwrap_or(&line);
+        for c in line.chars() {
+            if c == '|' {
+                buf.push('\\');
+            }
+
+            buf.push(c);
+        }
+    }
+}
@@ -20,11 +20,21 @@
 mod compact;
 mod util;
 
+#[cfg(feature = "std")]
+mod column_chunks;
+#[cfg(feature = "std")]
+mod csv;
 #[cfg(feature = "std")]
 mod extended;
 #[cfg(feature = "std")]
 mod iter;
 #[cfg(feature = "std")]
+mod live;
+#[cfg(feature = "std")]
+mod markdown;
+#[cfg(feature = "std")]
+mod pages;
+#[cfg(feature = "std")]
 mod table;
 #[cfg(feature = "std")]
 mod table_pool;
@@ -35,7 +45,27 @@ pub use table::Table;
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-pub use iter::IterTable;
+pub use pages::{PageLimit, Pages};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use column_chunks::ColumnChunks;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use csv::Csv;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use markdown::Markdown;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use iter::{IterTable, IterTableStream};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use live::LiveTable;
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
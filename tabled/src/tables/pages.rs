@@ -0,0 +1,196 @@
+//! This module contains a [`Pages`] iterator which splits a [`Table`] into pages.
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        config::ColoredConfig,
+        dimension::CompleteDimensionVecRecords,
+        records::{
+            vec_records::{Text, VecRecords},
+            ExactRecords, Records,
+        },
+    },
+    settings::{
+        filter::remove_rows,
+        sort::{find_pinned_rows, find_row_blocks},
+        TableOption,
+    },
+    Table,
+};
+
+/// A limit of a page used by [`Table::paginate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PageLimit {
+    /// A maximum number of rows on a page, not including repeated header rows.
+    Rows(usize),
+    /// A maximum height of a rendered page, including borders and repeated header rows.
+    ///
+    /// If even a single row doesn't fit, the page will contain it anyway.
+    Height(usize),
+}
+
+/// An iterator over rendered pages of a [`Table`].
+///
+/// Each page repeats the header rows of the table,
+/// which are the leading panels (like [`Panel::header`]) and the first row.
+/// See [`Pages::header`] to change the number of such rows.
+///
+/// All pages use the same column widths and row heights as the whole table,
+/// so the columns are aligned across pages.
+///
+/// It's created by [`Table::paginate`].
+///
+/// [`Panel::header`]: crate::settings::Panel::header
+#[derive(Debug, Clone)]
+pub struct Pages<'a> {
+    table: &'a Table,
+    limit: PageLimit,
+    header: usize,
+    state: Option<State>,
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    widths: Vec<usize>,
+    heights: Vec<usize>,
+    count_header_rows: usize,
+    blocks: Vec<(usize, usize)>,
+    next_block: usize,
+    is_first_page: bool,
+}
+
+impl<'a> Pages<'a> {
+    pub(crate) fn new(table: &'a Table, limit: PageLimit) -> Self {
+        Self {
+            table,
+            limit,
+            header: 1,
+            state: None,
+        }
+    }
+
+    /// Set an amount of first rows (not including panels) which are repeated on each page.
+    ///
+    /// By default it's 1.
+    pub fn header(mut self, count: usize) -> Self {
+        self.header = count;
+        self.state = None;
+        self
+    }
+
+    fn init_state(&self) -> State {
+        let records = self.table.get_records();
+        let cfg = self.table.get_config();
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let (widths, heights) = self.table.get_dimension().into_inner();
+        let widths = widths.unwrap_or_default();
+        let heights = heights.unwrap_or_default();
+
+        let pinned = find_pinned_rows(cfg, count_rows, count_columns, self.header);
+        let count_header_rows = pinned.iter().take_while(|&&is_pinned| is_pinned).count();
+        let blocks = find_row_blocks(cfg, (count_header_rows, count_rows), count_columns);
+
+        State {
+            widths,
+            heights,
+            count_header_rows,
+            blocks,
+            next_block: 0,
+            is_first_page: true,
+        }
+    }
+
+    fn page_height(&self, state: &State, end: usize) -> usize {
+        let cfg = self.table.get_config();
+        let start = state.blocks[state.next_block].0;
+        let count_rows = state.count_header_rows + end - start;
+
+        let header = state.heights[..state.count_header_rows]
+            .iter()
+            .sum::<usize>();
+        let content = state.heights[start..end].iter().sum::<usize>();
+        let lines = cfg.count_horizontal(count_rows);
+        let margin = cfg.get_margin();
+
+        header + content + lines + margin.top.size + margin.bottom.size
+    }
+
+    fn take_page(&self, state: &State) -> usize {
+        let mut next = state.next_block;
+        while next < state.blocks.len() {
+            let (_, end) = state.blocks[next];
+            let start = state.blocks[state.next_block].0;
+
+            let fits = match self.limit {
+                PageLimit::Rows(count) => end - start <= count,
+                PageLimit::Height(height) => self.page_height(state, end) <= height,
+            };
+
+            if !fits && next > state.next_block {
+                break;
+            }
+
+            next += 1;
+        }
+
+        next
+    }
+}
+
+impl Iterator for Pages<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut state = match self.state.take() {
+            Some(state) => state,
+            None => self.init_state(),
+        };
+
+        let is_done = state.next_block == state.blocks.len();
+        if is_done && !(state.is_first_page && state.count_header_rows > 0) {
+            self.state = Some(state);
+            return None;
+        }
+
+        let next_block = self.take_page(&state);
+
+        let count_rows = self.table.count_rows();
+        let mut keep = vec![false; count_rows];
+        keep[..state.count_header_rows]
+            .iter_mut()
+            .for_each(|row| *row = true);
+        for &(start, end) in &state.blocks[state.next_block..next_block] {
+            keep[start..end].iter_mut().for_each(|row| *row = true);
+        }
+
+        let heights = (0..count_rows)
+            .filter(|&row| keep[row])
+            .map(|row| state.heights[row])
+            .collect::<Vec<_>>();
+
+        let mut page = self.table.clone();
+        let _ = page.with(KeepRows(&keep));
+
+        let mut dims = CompleteDimensionVecRecords::default();
+        dims.set_widths(state.widths.clone());
+        dims.set_heights(heights);
+        page.set_dimension(dims);
+
+        state.next_block = next_block;
+        state.is_first_page = false;
+        self.state = Some(state);
+
+        Some(page.to_string())
+    }
+}
+
+struct KeepRows<'a>(&'a [bool]);
+
+impl<D> TableOption<VecRecords<Text<String>>, ColoredConfig, D> for KeepRows<'_> {
+    fn change(self, records: &mut VecRecords<Text<String>>, cfg: &mut ColoredConfig, _: &mut D) {
+        remove_rows(records, cfg, self.0);
+    }
+}
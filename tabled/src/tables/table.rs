@@ -1,7 +1,7 @@
 //! This module contains a main table representation [`Table`].
 
 use core::ops::DerefMut;
-use std::{borrow::Cow, fmt, iter::FromIterator};
+use std::{borrow::Cow, cmp, fmt, iter::FromIterator};
 
 use crate::{
     builder::Builder,
@@ -11,15 +11,25 @@ use crate::{
             AlignmentHorizontal, ColorMap, ColoredConfig, CompactConfig, Entity, Formatting,
             Indent, Sides, SpannedConfig,
         },
-        dimension::{CompleteDimensionVecRecords, Dimension, Estimate, PeekableDimension},
+        dimension::{
+            CompleteDimensionVecRecords, Dimension, Estimate, PeekableDimension,
+            SpannedVecRecordsDimension,
+        },
         records::{
             vec_records::{Text, VecRecords},
             ExactRecords, Records,
         },
         PeekableGrid,
     },
-    settings::{object::Object, CellOption, Style, TableOption},
-    Tabled,
+    settings::{
+        formatting::align_decimal_points,
+        object::{Columns, Object},
+        sort::strip,
+        CellOption, Direction, Style, TableOption,
+    },
+    tables::{ColumnChunks, Csv, Markdown, PageLimit, Pages},
+    util::bidi,
+    HeaderProvider, KeyValue, Tabled,
 };
 
 /// The structure provides an interface for building a table for types that implements [`Tabled`].
@@ -67,19 +77,171 @@ impl Table {
     ///
     /// If you use a reference iterator you'd better use [`FromIterator`] instead.
     /// As it has a different lifetime constraints and make less copies therefore.
+    ///
+    /// The groups of columns given by [`Tabled::column_groups`] are rendered as an extra header row.
+    ///
+    /// The settings of columns given by [`Tabled::column_settings`] are applied,
+    /// and then the table is passed to [`Tabled::present`] (if [`Tabled::HAS_PRESENTATION`] is set).
     pub fn new<I, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Tabled,
+    {
+        Self::from_tabled(iter, T::headers())
+    }
+
+    /// Creates a Table instance the same way as [`Table::new`],
+    /// but takes column names from a [`HeaderProvider`] by [`Tabled::header_keys`].
+    ///
+    /// A column without a key, or with a key which the provider doesn't know,
+    /// uses a name from [`Tabled::headers`].
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tabled::{Table, Tabled};
+    ///
+    /// #[derive(Tabled)]
+    /// struct User {
+    ///     #[tabled(header_key = "user.name")]
+    ///     name: &'static str,
+    /// }
+    ///
+    /// let mut french = HashMap::new();
+    /// french.insert("user.name", "Nom");
+    ///
+    /// let table = Table::new_with_headers([User { name: "Anna" }], &french);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "+------+\n",
+    ///         "| Nom  |\n",
+    ///         "+------+\n",
+    ///         "| Anna |\n",
+    ///         "+------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn new_with_headers<I, T, P>(iter: I, provider: &P) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Tabled,
+        P: HeaderProvider + ?Sized,
+    {
+        let keys = T::header_keys();
+        let headers = T::headers()
+            .into_iter()
+            .enumerate()
+            .map(|(col, header)| {
+                keys.get(col)
+                    .and_then(Option::as_ref)
+                    .and_then(|key| provider.header(key))
+                    .map_or(header, |text| Cow::Owned(text.into_owned()))
+            })
+            .collect();
+
+        Self::from_tabled(iter, headers)
+    }
+
+    /// Creates a Table out of map entries (or any other key-value pairs),
+    /// with `Key` and `Value` columns.
+    ///
+    /// The entries are kept in an order of the iterator;
+    /// use [`Table::from_map_sorted`] for a map with an arbitrary order like [`HashMap`].
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use tabled::{settings::Style, Table};
+    ///
+    /// let mut versions = BTreeMap::new();
+    /// versions.insert("papergrid", "0.12");
+    /// versions.insert("tabled", "0.16");
+    ///
+    /// let mut table = Table::from_map(&versions);
+    /// table.with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " Key       | Value \n",
+    ///         "-----------+-------\n",
+    ///         " papergrid | 0.12  \n",
+    ///         " tabled    | 0.16  ",
+    ///     ),
+    /// );
+    /// ```
+    ///
+    /// [`HashMap`]: std::collections::HashMap
+    pub fn from_map<I, K, V>(map: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: fmt::Display,
+        V: fmt::Display,
+    {
+        Self::new(map.into_iter().map(KeyValue::from))
+    }
+
+    /// Creates a Table out of map entries the same way as [`Table::from_map`],
+    /// but sorts them by keys.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tabled::{settings::Style, Table};
+    ///
+    /// let mut scores = HashMap::new();
+    /// scores.insert("Tom", 7);
+    /// scores.insert("Ann", 9);
+    ///
+    /// let mut table = Table::from_map_sorted(scores);
+    /// table.with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " Key | Value \n",
+    ///         "-----+-------\n",
+    ///         " Ann | 9     \n",
+    ///         " Tom | 7     ",
+    ///     ),
+    /// );
+    /// ```
+    pub fn from_map_sorted<I, K, V>(map: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: fmt::Display + Ord,
+        V: fmt::Display,
+    {
+        let mut entries = map.into_iter().collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Self::from_map(entries)
+    }
+
+    fn from_tabled<I, T>(iter: I, headers: Vec<Cow<'_, str>>) -> Self
     where
         I: IntoIterator<Item = T>,
         T: Tabled,
     {
         let mut header = Vec::with_capacity(T::LENGTH);
-        for text in T::headers() {
+        for text in headers {
             let text = text.into_owned();
             let cell = Text::new(text);
             header.push(cell);
         }
 
-        let mut records = vec![header];
+        let mut groups = T::column_groups();
+        groups.resize(T::LENGTH, None);
+        let has_groups = groups.iter().any(Option::is_some);
+
+        let mut records = Vec::new();
+        if has_groups {
+            records.push(group_header(&groups, &header));
+        }
+
+        records.push(header);
+
+        // rows are kept only if they are needed by a presentation
+        let mut rows = Vec::new();
         for row in iter.into_iter() {
             let mut list = Vec::with_capacity(T::LENGTH);
             for text in row.fields().into_iter() {
@@ -90,15 +252,37 @@ impl Table {
             }
 
             records.push(list);
+
+            if T::HAS_PRESENTATION {
+                rows.push(row);
+            }
         }
 
         let records = VecRecords::new(records);
 
-        Self {
+        let mut config = configure_grid();
+        if has_groups {
+            set_group_spans(&mut config, &groups);
+        }
+
+        let mut table = Self {
             records,
-            config: ColoredConfig::new(configure_grid()),
+            config: ColoredConfig::new(config),
             dimension: CompleteDimensionVecRecords::default(),
+        };
+
+        for (col, settings) in T::column_settings().into_iter().enumerate() {
+            if col < T::LENGTH && !settings.is_empty() {
+                let _ = table.modify(Columns::single(col), settings);
+            }
+        }
+
+        if T::HAS_PRESENTATION {
+            let rows = rows.iter().collect::<Vec<_>>();
+            T::present(&mut table, &rows);
         }
+
+        table
     }
 
     /// Creates a builder from a data set given.
@@ -232,8 +416,8 @@ impl Table {
 
     /// Returns total widths of a table, including margin and horizontal lines.
     pub fn total_height(&self) -> usize {
-        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
-        dims.estimate(&self.records, self.config.as_ref());
+        let records = use_decimal_alignment(self);
+        let dims = use_dimension(self, &records, self.config.as_ref());
 
         let total = (0..self.count_rows())
             .map(|row| dims.get_height(row))
@@ -247,8 +431,8 @@ impl Table {
 
     /// Returns total widths of a table, including margin and vertical lines.
     pub fn total_width(&self) -> usize {
-        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
-        dims.estimate(&self.records, self.config.as_ref());
+        let records = use_decimal_alignment(self);
+        let dims = use_dimension(self, &records, self.config.as_ref());
 
         let total = (0..self.count_columns())
             .map(|col| dims.get_width(col))
@@ -279,6 +463,130 @@ impl Table {
     pub fn get_records_mut(&mut self) -> &mut VecRecords<Text<String>> {
         &mut self.records
     }
+
+    /// Splits the table into pages, repeating the header rows on each of them.
+    ///
+    /// ```
+    /// use tabled::{Table, tables::PageLimit, settings::Style};
+    ///
+    /// let data = [1, 2, 3];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Style::psql());
+    ///
+    /// let pages = table.paginate(PageLimit::Rows(2)).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(pages, [" i32 \n-----\n 1   \n 2   ", " i32 \n-----\n 3   "]);
+    /// ```
+    pub fn paginate(&self, limit: PageLimit) -> Pages<'_> {
+        Pages::new(self, limit)
+    }
+
+    /// Splits the table by columns into parts which are not wider than a given width,
+    /// repeating the key columns (the first column by default) in each of them.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Style};
+    ///
+    /// let data = [("Tom", 27, "Minsk"), ("Ann", 9, "Grodno")];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Style::psql());
+    ///
+    /// let parts = table.split_columns(12).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     parts,
+    ///     [
+    ///         " &str | i32 \n------+-----\n Tom  | 27  \n Ann  | 9   ",
+    ///         " &str | &str   \n------+--------\n Tom  | Minsk  \n Ann  | Grodno ",
+    ///     ]
+    /// );
+    /// ```
+    pub fn split_columns(&self, width: usize) -> ColumnChunks<'_> {
+        ColumnChunks::new(self, width)
+    }
+
+    /// Returns a [`Csv`] writer of the table content.
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Tom", 27), ("Ann", 9)]);
+    ///
+    /// let mut out = Vec::new();
+    /// table.csv().write(&mut out).unwrap();
+    ///
+    /// assert_eq!(out, b"&str,i32\nTom,27\nAnn,9\n");
+    /// ```
+    pub fn csv(&self) -> Csv<'_> {
+        Csv::new(self, ',')
+    }
+
+    /// Returns the table content as CSV.
+    ///
+    /// See [`Table::csv`] for more options.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Disable, object::Rows}};
+    ///
+    /// let mut table = Table::new([("Tom", "Minsk\nBelarus"), ("Ann", "Grodno")]);
+    /// table.with(Disable::row(Rows::first()));
+    ///
+    /// assert_eq!(table.to_csv(), "Tom,\"Minsk\nBelarus\"\nAnn,Grodno\n");
+    /// ```
+    pub fn to_csv(&self) -> String {
+        self.csv().to_string()
+    }
+
+    /// Returns the table content as TSV (tab separated values).
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Tom", 27), ("Ann", 9)]);
+    ///
+    /// assert_eq!(table.to_tsv(), "&str\ti32\nTom\t27\nAnn\t9\n");
+    /// ```
+    pub fn to_tsv(&self) -> String {
+        self.csv().delimiter('\t').to_string()
+    }
+
+    /// Returns a [`Markdown`] renderer of the table.
+    pub fn markdown(&self) -> Markdown<'_> {
+        Markdown::new(self)
+    }
+
+    /// Returns the table as a GitHub flavored Markdown table.
+    ///
+    /// See [`Markdown`] for details.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Alignment, Padding}};
+    ///
+    /// let mut table = Table::new([("Tom", 27), ("Ann", 9)]);
+    /// table.with(Alignment::center()).with(Padding::new(3, 3, 0, 0));
+    ///
+    /// assert_eq!(
+    ///     table.to_markdown(),
+    ///     "| &str | i32 |\n| :---: | :---: |\n| Tom | 27 |\n| Ann | 9 |\n",
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        self.markdown().to_string()
+    }
+
+    /// Returns a dimension of the table with all widths and heights estimated.
+    pub(crate) fn get_dimension(&self) -> CompleteDimensionVecRecords<'static> {
+        let records = use_decimal_alignment(self);
+        use_dimension(self, &records, self.config.as_ref())
+    }
+
+    /// Sets a dimension of the table.
+    pub(crate) fn set_dimension(&mut self, dimension: CompleteDimensionVecRecords<'static>) {
+        self.dimension = dimension;
+    }
 }
 
 impl Default for Table {
@@ -298,18 +606,19 @@ impl fmt::Display for Table {
         }
 
         let config = use_format_configuration(f, self);
+        let records = use_decimal_alignment(self);
+        let (records, config) = use_direction(self, records, config);
         let colors = self.config.get_colors();
 
         if !self.dimension.is_empty() {
-            let mut dims = self.dimension.clone();
-            dims.estimate(&self.records, config.as_ref());
+            let dims = use_dimension(self, &records, &config);
 
-            print_grid(f, &self.records, &config, &dims, colors)
+            print_grid(f, &records, &config, &dims, colors)
         } else {
             let mut dims = PeekableDimension::default();
-            dims.estimate(&self.records, &config);
+            dims.estimate(records.as_ref(), &config);
 
-            print_grid(f, &self.records, &config, &dims, colors)
+            print_grid(f, &records, &config, &dims, colors)
         }
     }
 }
@@ -388,6 +697,44 @@ fn table_padding(alignment: fmt::Alignment, available: usize) -> (usize, usize)
     }
 }
 
+fn group_header(
+    groups: &[Option<Cow<'static, str>>],
+    header: &[Text<String>],
+) -> Vec<Text<String>> {
+    groups
+        .iter()
+        .zip(header)
+        .map(|(group, name)| match group {
+            Some(group) => Text::new(group.to_string()),
+            None => name.clone(),
+        })
+        .collect()
+}
+
+fn set_group_spans(cfg: &mut SpannedConfig, groups: &[Option<Cow<'static, str>>]) {
+    let mut col = 0;
+    while col < groups.len() {
+        let group = match &groups[col] {
+            Some(group) => group,
+            None => {
+                cfg.set_row_span((0, col), 2);
+                col += 1;
+                continue;
+            }
+        };
+
+        let span = groups[col..]
+            .iter()
+            .take_while(|g| g.as_ref() == Some(group))
+            .count();
+        if span > 1 {
+            cfg.set_column_span((0, col), span);
+        }
+
+        col += span;
+    }
+}
+
 fn configure_grid() -> SpannedConfig {
     let mut cfg = SpannedConfig::default();
     cfg.set_padding(
@@ -422,6 +769,101 @@ fn use_format_configuration<'a>(
     }
 }
 
+/// Reorders a text of cells into a visual order and mirrors their alignment,
+/// in case a [`Direction`] is set.
+///
+/// [`Direction`]: crate::settings::Direction
+fn use_direction<'a>(
+    table: &'a Table,
+    mut records: Cow<'a, VecRecords<Text<String>>>,
+    mut cfg: Cow<'a, SpannedConfig>,
+) -> (Cow<'a, VecRecords<Text<String>>>, Cow<'a, SpannedConfig>) {
+    if table.config.get_direction(Entity::Global).is_none() {
+        return (records, cfg);
+    }
+
+    for row in 0..table.count_rows() {
+        for col in 0..table.count_columns() {
+            let entity = Entity::Cell(row, col);
+            let text = records[row][col].as_ref();
+
+            let rtl = match table.config.get_direction(entity) {
+                Some(Direction::Rtl) => true,
+                Some(Direction::Auto) => bidi::get_text_direction(&strip(text)).unwrap_or(false),
+                _ => false,
+            };
+
+            if rtl {
+                let alignment = match cfg.get_alignment_horizontal(entity) {
+                    AlignmentHorizontal::Left => AlignmentHorizontal::Right,
+                    AlignmentHorizontal::Right => AlignmentHorizontal::Left,
+                    AlignmentHorizontal::Center => AlignmentHorizontal::Center,
+                };
+
+                cfg.to_mut().set_alignment_horizontal(entity, alignment);
+            }
+
+            let text = match bidi::reorder_text(text, rtl) {
+                Cow::Owned(text) => text,
+                Cow::Borrowed(_) => continue,
+            };
+
+            records.to_mut()[row][col] = Text::new(text);
+        }
+    }
+
+    (records, cfg)
+}
+
+/// Pads lines of cells so decimal points line up,
+/// in case a [`DecimalAlignment`] is set.
+///
+/// [`DecimalAlignment`]: crate::settings::formatting::DecimalAlignment
+fn use_decimal_alignment(table: &Table) -> Cow<'_, VecRecords<Text<String>>> {
+    if !table.config.has_decimal_points() {
+        return Cow::Borrowed(&table.records);
+    }
+
+    let changes = align_decimal_points(&table.records, &table.config);
+    if changes.is_empty() {
+        return Cow::Borrowed(&table.records);
+    }
+
+    let mut records = table.records.clone();
+    for ((row, col), text) in changes {
+        records[row][col] = Text::new(text);
+    }
+
+    Cow::Owned(records)
+}
+
+/// Estimates a dimension of rendered records.
+///
+/// As widths are cached before decimal points are aligned,
+/// they are widened in case the alignment made a column wider.
+fn use_dimension(
+    table: &Table,
+    records: &VecRecords<Text<String>>,
+    cfg: &SpannedConfig,
+) -> CompleteDimensionVecRecords<'static> {
+    let mut dims = table.dimension.clone();
+
+    if table.config.has_decimal_points() {
+        if let Some(widths) = dims.get_widths() {
+            let widths = widths
+                .iter()
+                .zip(SpannedVecRecordsDimension::width(records, cfg))
+                .map(|(&width, real)| cmp::max(width, real))
+                .collect();
+
+            dims.set_widths(widths);
+        }
+    }
+
+    dims.estimate(records, cfg);
+    dims
+}
+
 fn set_align_table(f: &fmt::Formatter<'_>, cfg: &mut SpannedConfig) {
     if let Some(alignment) = f.align() {
         let alignment = convert_fmt_alignment(alignment);
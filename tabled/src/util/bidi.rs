@@ -0,0 +1,281 @@
+//! A simplified implementation of the Unicode Bidirectional Algorithm (UAX #9).
+//!
+//! Only implicit levels are supported (no explicit embeddings, overrides or isolates),
+//! which is enough to put a plain text of a cell into a visual order.
+
+use std::borrow::Cow;
+
+use crate::grid::util::string::get_graphemes;
+
+/// A resolved bidirectional class of a character.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    /// A strong left-to-right character.
+    L,
+    /// A strong right-to-left character.
+    R,
+    /// A european number.
+    EN,
+    /// An arabic number.
+    AN,
+    /// A separator which may appear inside of a number.
+    CS,
+    /// A non spacing mark, which takes a class of a previous character.
+    NSM,
+    /// A whitespace.
+    WS,
+    /// Any other neutral character.
+    ON,
+}
+
+/// Returns a direction of a text by its first strong character,
+/// `true` stands for right-to-left.
+///
+/// `None` is returned if there's no strong characters.
+pub(crate) fn get_text_direction(text: &str) -> Option<bool> {
+    text.chars().find_map(|c| match classify(c) {
+        Class::L => Some(false),
+        Class::R => Some(true),
+        _ => None,
+    })
+}
+
+/// Reorders each line of a text from a logical into a visual order,
+/// `rtl` sets a base direction.
+///
+/// Lines with ANSI escape sequences are left untouched.
+pub(crate) fn reorder_text(text: &str, rtl: bool) -> Cow<'_, str> {
+    let lines = text
+        .split('\n')
+        .map(|line| reorder_line(line, rtl))
+        .collect::<Vec<_>>();
+
+    if lines.iter().all(|line| matches!(line, Cow::Borrowed(_))) {
+        return Cow::Borrowed(text);
+    }
+
+    Cow::Owned(lines.join("\n"))
+}
+
+fn reorder_line(line: &str, rtl: bool) -> Cow<'_, str> {
+    if line.contains('\u{1b}') {
+        return Cow::Borrowed(line);
+    }
+
+    let has_rtl = line
+        .chars()
+        .any(|c| matches!(classify(c), Class::R | Class::AN));
+    if !rtl && !has_rtl {
+        return Cow::Borrowed(line);
+    }
+
+    let base = if rtl { Class::R } else { Class::L };
+    let base_level = rtl as u8;
+
+    let graphemes = get_graphemes(line).collect::<Vec<_>>();
+    let mut classes = graphemes
+        .iter()
+        .map(|g| g.chars().next().map_or(Class::ON, classify))
+        .collect::<Vec<_>>();
+
+    resolve_weak_types(&mut classes, base);
+    resolve_neutral_types(&mut classes, base);
+
+    let mut levels = classes
+        .iter()
+        .map(|class| match (class, base_level % 2) {
+            (Class::R, 0) => base_level + 1,
+            (Class::EN, 0) | (Class::AN, 0) => base_level + 2,
+            (Class::L, 1) | (Class::EN, 1) | (Class::AN, 1) => base_level + 1,
+            _ => base_level,
+        })
+        .collect::<Vec<_>>();
+
+    // trailing whitespaces are reset to a paragraph level
+    for (level, g) in levels.iter_mut().zip(&graphemes).rev() {
+        if !g.chars().all(char::is_whitespace) {
+            break;
+        }
+
+        *level = base_level;
+    }
+
+    let mut order = (0..graphemes.len()).collect::<Vec<_>>();
+    let max_level = levels.iter().copied().max().unwrap_or(base_level);
+    let min_odd_level = levels
+        .iter()
+        .copied()
+        .filter(|level| level % 2 == 1)
+        .min()
+        .unwrap_or(max_level + 1);
+
+    for level in (min_odd_level..=max_level).rev() {
+        let mut i = 0;
+        while i < order.len() {
+            if levels[order[i]] < level {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            while i < order.len() && levels[order[i]] >= level {
+                i += 1;
+            }
+
+            order[start..i].reverse();
+        }
+    }
+
+    let mut buf = String::with_capacity(line.len());
+    for i in order {
+        let g = graphemes[i];
+        match mirror(g) {
+            Some(c) if levels[i] % 2 == 1 => buf.push(c),
+            _ => buf.push_str(g),
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
+fn resolve_weak_types(classes: &mut [Class], base: Class) {
+    // W1
+    let mut prev = base;
+    for class in classes.iter_mut() {
+        if *class == Class::NSM {
+            *class = prev;
+        }
+
+        prev = *class;
+    }
+
+    // W4
+    for i in 1..classes.len().saturating_sub(1) {
+        let (lhs, rhs) = (classes[i - 1], classes[i + 1]);
+        if classes[i] == Class::CS && lhs == rhs && matches!(lhs, Class::EN | Class::AN) {
+            classes[i] = lhs;
+        }
+    }
+
+    // W6
+    for class in classes.iter_mut() {
+        if *class == Class::CS {
+            *class = Class::ON;
+        }
+    }
+
+    // W7
+    let mut strong = base;
+    for class in classes.iter_mut() {
+        match *class {
+            Class::L | Class::R => strong = *class,
+            Class::EN if strong == Class::L => *class = Class::L,
+            _ => {}
+        }
+    }
+}
+
+fn resolve_neutral_types(classes: &mut [Class], base: Class) {
+    let strong = |class: Class| match class {
+        Class::L => Some(Class::L),
+        Class::R | Class::EN | Class::AN => Some(Class::R),
+        _ => None,
+    };
+
+    let mut i = 0;
+    while i < classes.len() {
+        if strong(classes[i]).is_some() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < classes.len() && strong(classes[i]).is_none() {
+            i += 1;
+        }
+
+        let before = start
+            .checked_sub(1)
+            .and_then(|i| strong(classes[i]))
+            .unwrap_or(base);
+        let after = classes.get(i).copied().and_then(strong).unwrap_or(base);
+
+        // N1 and N2
+        let class = if before == after { before } else { base };
+        for c in &mut classes[start..i] {
+            *c = class;
+        }
+    }
+}
+
+fn classify(c: char) -> Class {
+    match c as u32 {
+        0x0030..=0x0039 => Class::EN,
+        0x0660..=0x0669 | 0x06F0..=0x06F9 => Class::AN,
+        0x0591..=0x05BD | 0x05BF | 0x05C1..=0x05C2 | 0x05C4..=0x05C5 | 0x05C7 => Class::NSM,
+        0x0610..=0x061A | 0x064B..=0x065F | 0x0670 | 0x06D6..=0x06DC | 0x06DF..=0x06E4 => {
+            Class::NSM
+        }
+        0x0300..=0x036F | 0x200D | 0xFE00..=0xFE0F => Class::NSM,
+        0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF | 0x10800..=0x10FFF => Class::R,
+        0x1E800..=0x1EFFF => Class::R,
+        0x200F => Class::R,
+        0x200E => Class::L,
+        _ if c == ',' || c == '.' || c == ':' || c == '/' => Class::CS,
+        _ if c.is_whitespace() => Class::WS,
+        _ if c.is_alphanumeric() => Class::L,
+        _ => Class::ON,
+    }
+}
+
+fn mirror(g: &str) -> Option<char> {
+    let mut chars = g.chars();
+    let c = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+
+    let c = match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reorder_test() {
+        assert_eq!(reorder_text("Hello World", false), "Hello World");
+        assert_eq!(reorder_text("שלום", false), "םולש");
+        assert_eq!(reorder_text("שלום", true), "םולש");
+        assert_eq!(reorder_text("Hello", true), "Hello");
+        assert_eq!(reorder_text("abc שלום עולם", false), "abc םלוע םולש");
+        assert_eq!(reorder_text("שלום abc def", true), "abc def םולש");
+        assert_eq!(reorder_text("מחיר 1,000", true), "1,000 ריחמ");
+        assert_eq!(reorder_text("(שלום)", true), "(םולש)");
+        assert_eq!(reorder_text("שלום \nעולם", true), " םולש\nםלוע");
+        assert_eq!(reorder_text("abc.", true), ".abc");
+    }
+
+    #[test]
+    fn text_direction_test() {
+        assert_eq!(get_text_direction("שלום"), Some(true));
+        assert_eq!(get_text_direction("123 مرحبا"), Some(true));
+        assert_eq!(get_text_direction("Hello שלום"), Some(false));
+        assert_eq!(get_text_direction("123 ..."), None);
+    }
+}
@@ -1,2 +1,4 @@
 #[cfg(feature = "std")]
+pub(crate) mod bidi;
+#[cfg(feature = "std")]
 pub(crate) mod string;
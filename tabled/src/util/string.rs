@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use crate::grid::util::string::get_char_width;
+use crate::grid::util::string::{get_grapheme_width, get_graphemes};
 
 /// The function cuts the string to a specific width.
 /// Preserving colors with `ansi` feature on.
@@ -50,6 +50,12 @@ pub(crate) fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
     {
         const REPLACEMENT: char = '\u{FFFD}';
 
+        let (text, links) = Hyperlinks::split(s);
+        if !links.is_empty() {
+            let text = cut_str(&text, width);
+            return Cow::Owned(links.restore(&text));
+        }
+
         let stripped = ansi_str::AnsiStr::ansi_strip(s);
         let (length, cutwidth, csize) = split_at_width(&stripped, width);
         let mut buf = ansi_str::AnsiStr::ansi_cut(s, ..length);
@@ -94,90 +100,193 @@ pub(crate) fn cut_str2(text: &str, width: usize) -> Cow<'_, str> {
 pub(crate) fn split_at_width(s: &str, at_width: usize) -> (usize, usize, usize) {
     let mut length = 0;
     let mut width = 0;
-    for c in s.chars() {
+    for grapheme in get_graphemes(s) {
         if width == at_width {
             break;
         };
 
-        let c_width = get_char_width(c);
-        let c_length = c.len_utf8();
+        let g_width = get_grapheme_width(grapheme);
+        let g_length = grapheme.len();
 
         // We cut the chars which takes more then 1 symbol to display,
         // in order to archive the necessary width.
-        if width + c_width > at_width {
-            return (length, width, c_length);
+        if width + g_width > at_width {
+            return (length, width, g_length);
         }
 
-        width += c_width;
-        length += c_length;
+        width += g_width;
+        length += g_length;
     }
 
     (length, width, 0)
 }
 
-/// Strip OSC codes from `s`. If `s` is a single OSC8 hyperlink, with no other text, then return
-/// (s_with_all_hyperlinks_removed, Some(url)). If `s` does not meet this description, then return
-/// (s_with_all_hyperlinks_removed, None). Any ANSI color sequences in `s` will be retained. See
-/// <https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda>
+/// OSC 8 hyperlinks of a text.
 ///
-/// The function is based on Dan Davison <https://github.com/dandavison> delta <https://github.com/dandavison/delta> ansi library.
+/// A text is split into a text without hyperlinks and a list of its graphemes with their links,
+/// so after the text is changed (cut or wrapped) the links can be put back.
+/// See <https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda>.
+#[cfg(feature = "ansi")]
+#[derive(Debug, Default)]
+pub(crate) struct Hyperlinks<'a> {
+    graphemes: Vec<(&'a str, Option<&'a str>)>,
+}
+
 #[cfg(feature = "ansi")]
-pub(crate) fn strip_osc(text: &str) -> (String, Option<String>) {
-    #[derive(Debug)]
-    enum ExtractOsc8HyperlinkState {
-        ExpectOsc8Url,
-        ExpectFirstText,
-        ExpectMoreTextOrTerminator,
-        SeenOneHyperlink,
-        WillNotReturnUrl,
+impl<'a> Hyperlinks<'a> {
+    const OSC8: &'static str = "\x1b]8;";
+    const ST: &'static str = "\x1b\\";
+
+    /// Removes hyperlinks from a text.
+    pub(crate) fn split(text: &'a str) -> (Cow<'a, str>, Self) {
+        if !text.contains(Self::OSC8) {
+            return (Cow::Borrowed(text), Self::default());
+        }
+
+        let mut buf = String::with_capacity(text.len());
+        let mut graphemes = Vec::new();
+        let mut link = None;
+        let mut is_link_found = false;
+        let mut is_osc8 = false;
+
+        for el in ansitok::parse_ansi(text) {
+            let part = &text[el.start()..el.end()];
+            match el.kind() {
+                ansitok::ElementKind::Osc => match parse_osc8(part) {
+                    Some(url) => {
+                        link = url;
+                        is_link_found = true;
+                        is_osc8 = true;
+                    }
+                    None => buf.push_str(part),
+                },
+                // a string terminator of OSC 8
+                ansitok::ElementKind::Esc if is_osc8 && part == "\\" => is_osc8 = false,
+                ansitok::ElementKind::Text => {
+                    is_osc8 = false;
+                    buf.push_str(part);
+                    graphemes.extend(get_graphemes(part).map(|g| (g, link)));
+                }
+                _ => {
+                    is_osc8 = false;
+                    buf.push_str(part);
+                }
+            }
+        }
+
+        if !is_link_found {
+            return (Cow::Borrowed(text), Self::default());
+        }
+
+        (Cow::Owned(buf), Self { graphemes })
     }
 
-    use ExtractOsc8HyperlinkState::*;
+    /// Checks whether there's no hyperlinks.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.graphemes.is_empty()
+    }
 
-    let mut url = None;
-    let mut state = ExpectOsc8Url;
-    let mut buf = String::with_capacity(text.len());
+    /// Puts hyperlinks back into a text which was made out of a text returned by [`Hyperlinks::split`].
+    ///
+    /// A text may be cut and wrapped, so a link is closed at the end of each line
+    /// and re-opened on the next one.
+    pub(crate) fn restore(&self, text: &str) -> String {
+        let mut buf = String::with_capacity(text.len());
+        let mut pos = 0;
+        let mut opened = None;
+
+        for el in ansitok::parse_ansi(text) {
+            let part = &text[el.start()..el.end()];
+            if el.kind() != ansitok::ElementKind::Text {
+                buf.push_str(part);
+                continue;
+            }
 
-    for el in ansitok::parse_ansi(text) {
-        match el.kind() {
-            ansitok::ElementKind::Osc => match state {
-                ExpectOsc8Url => {
-                    url = Some(&text[el.start()..el.end()]);
-                    state = ExpectFirstText;
-                }
-                ExpectMoreTextOrTerminator => state = SeenOneHyperlink,
-                _ => state = WillNotReturnUrl,
-            },
-            ansitok::ElementKind::Sgr => buf.push_str(&text[el.start()..el.end()]),
-            ansitok::ElementKind::Csi => buf.push_str(&text[el.start()..el.end()]),
-            ansitok::ElementKind::Esc => {}
-            ansitok::ElementKind::Text => {
-                buf.push_str(&text[el.start()..el.end()]);
-                match state {
-                    ExpectFirstText => state = ExpectMoreTextOrTerminator,
-                    ExpectMoreTextOrTerminator => {}
-                    _ => state = WillNotReturnUrl,
+            for grapheme in get_graphemes(part) {
+                let link = match grapheme {
+                    "\n" => {
+                        if self.graphemes.get(pos).map(|(g, _)| *g) == Some("\n") {
+                            pos += 1;
+                        }
+
+                        None
+                    }
+                    _ => self.find_link(&mut pos, grapheme),
+                };
+
+                if link != opened {
+                    if opened.is_some() {
+                        buf.push_str(Self::OSC8);
+                        buf.push(';');
+                        buf.push_str(Self::ST);
+                    }
+
+                    if let Some(url) = link {
+                        buf.push_str(Self::OSC8);
+                        buf.push_str(url);
+                        buf.push_str(Self::ST);
+                    }
+
+                    opened = link;
                 }
+
+                buf.push_str(grapheme);
             }
         }
+
+        if opened.is_some() {
+            buf.push_str(Self::OSC8);
+            buf.push(';');
+            buf.push_str(Self::ST);
+        }
+
+        buf
     }
 
-    match state {
-        WillNotReturnUrl => (buf, None),
-        _ => {
-            let url = url.and_then(|s| {
-                s.strip_prefix("\x1b]8;;")
-                    .and_then(|s| s.strip_suffix('\x1b'))
-            });
-            if let Some(url) = url {
-                (buf, Some(url.to_string()))
-            } else {
-                (buf, None)
+    /// Finds a link of a grapheme, skipping graphemes which were dropped.
+    fn find_link(&self, pos: &mut usize, grapheme: &str) -> Option<&'a str> {
+        const REPLACEMENT: &str = "\u{FFFD}";
+
+        let (origin, link) = *self.graphemes.get(*pos)?;
+        if origin == grapheme {
+            *pos += 1;
+            return link;
+        }
+
+        match grapheme {
+            // a grapheme which was replaced as it was too wide
+            REPLACEMENT => link,
+            // an added indent
+            " " => None,
+            _ => {
+                let i = self.graphemes[*pos..]
+                    .iter()
+                    .position(|(g, _)| *g == grapheme)?;
+                *pos += i + 1;
+                self.graphemes[*pos - 1].1
             }
         }
     }
 }
 
+/// Returns `params;url` part of OSC 8 sequence,
+/// an empty string is returned as `None` as it's a link end.
+#[cfg(feature = "ansi")]
+fn parse_osc8(osc: &str) -> Option<Option<&str>> {
+    let body = osc.strip_prefix("\x1b]8;")?;
+    let body = body
+        .strip_suffix('\x1b')
+        .or_else(|| body.strip_suffix('\x07'))
+        .unwrap_or(body);
+
+    let (_, url) = body.split_once(';')?;
+    if url.is_empty() {
+        return Some(None);
+    }
+
+    Some(Some(body))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,8 +310,6 @@ mod tests {
         assert_eq!(cut_str("😳😳😳😳😳", 20), "😳😳😳😳😳");
 
         assert_eq!(cut_str("🏳️🏳️", 0), "");
-        assert_eq!(cut_str("🏳️🏳️", 1), "🏳");
-        assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}🏳");
         assert_eq!(get_line_width("🏳️🏳️"), get_line_width("🏳\u{fe0f}🏳\u{fe0f}"));
 
         assert_eq!(cut_str("🎓", 1), "�");
@@ -215,14 +322,33 @@ mod tests {
         assert_eq!(cut_str("🩰", 2), "🩰");
 
         assert_eq!(cut_str("👍🏿", 1), "�");
-        assert_eq!(cut_str("👍🏿", 2), "👍");
-        assert_eq!(cut_str("👍🏿", 3), "👍�");
         assert_eq!(cut_str("👍🏿", 4), "👍🏿");
 
-        assert_eq!(cut_str("🇻🇬", 1), "🇻");
         assert_eq!(cut_str("🇻🇬", 2), "🇻🇬");
         assert_eq!(cut_str("🇻🇬", 3), "🇻🇬");
         assert_eq!(cut_str("🇻🇬", 4), "🇻🇬");
+
+        #[cfg(not(feature = "grapheme"))]
+        {
+            assert_eq!(cut_str("🏳️🏳️", 1), "🏳");
+            assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}🏳");
+
+            assert_eq!(cut_str("👍🏿", 2), "👍");
+            assert_eq!(cut_str("👍🏿", 3), "👍�");
+
+            assert_eq!(cut_str("🇻🇬", 1), "🇻");
+        }
+
+        #[cfg(feature = "grapheme")]
+        {
+            assert_eq!(cut_str("🏳️🏳️", 1), "�");
+            assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}");
+
+            assert_eq!(cut_str("👍🏿", 2), "👍🏿");
+            assert_eq!(cut_str("👍🏿", 3), "👍🏿");
+
+            assert_eq!(cut_str("🇻🇬", 1), "�");
+        }
     }
 
     #[cfg(feature = "ansi")]
@@ -253,11 +379,25 @@ mod tests {
         let emojies = "🏳️🏳️".red().on_bright_black().to_string();
 
         assert_eq!(cut_str(&emojies, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
-        assert_eq!(cut_str(&emojies, 1), "\u{1b}[31;100m🏳\u{1b}[39m\u{1b}[49m");
-        assert_eq!(
-            cut_str(&emojies, 2),
-            "\u{1b}[31;100m🏳\u{fe0f}🏳\u{1b}[39m\u{1b}[49m"
-        );
+
+        #[cfg(not(feature = "grapheme"))]
+        {
+            assert_eq!(cut_str(&emojies, 1), "\u{1b}[31;100m🏳\u{1b}[39m\u{1b}[49m");
+            assert_eq!(
+                cut_str(&emojies, 2),
+                "\u{1b}[31;100m🏳\u{fe0f}🏳\u{1b}[39m\u{1b}[49m"
+            );
+        }
+
+        #[cfg(feature = "grapheme")]
+        {
+            assert_eq!(cut_str(&emojies, 1), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m�");
+            assert_eq!(
+                cut_str(&emojies, 2),
+                "\u{1b}[31;100m🏳\u{fe0f}\u{1b}[39m\u{1b}[49m"
+            );
+        }
+
         assert_eq!(
             get_line_width(&emojies),
             get_line_width("\u{1b}[31;100m🏳\u{fe0f}🏳\u{fe0f}\u{1b}[39m\u{1b}[49m")
@@ -280,43 +420,45 @@ mod tests {
 
     #[test]
     #[cfg(feature = "ansi")]
-    fn test_srip_osc() {
-        assert_eq!(
-            strip_osc("just a string here"),
-            (String::from("just a string here"), None)
-        );
-        assert_eq!(
-            strip_osc("/etc/rc.conf"),
-            (String::from("/etc/rc.conf"), None)
-        );
-        assert_eq!(
-            strip_osc(
-                "https://gitlab.com/finestructure/swiftpackageindex-builder/-/pipelines/1054655982"
-            ),
-            (String::from("https://gitlab.com/finestructure/swiftpackageindex-builder/-/pipelines/1054655982"), None)
-        );
+    fn hyperlinks_test() {
+        fn link(url: &str, text: &str) -> String {
+            format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+        }
 
+        let text = "just a string here";
+        let (stripped, links) = Hyperlinks::split(text);
+        assert_eq!(stripped, text);
+        assert!(links.is_empty());
+
+        let text = format!("see {} or {}", link("a.com", "A"), link("b.com", "site B"));
+        let (stripped, links) = Hyperlinks::split(&text);
+        assert_eq!(stripped, "see A or site B");
+        assert_eq!(links.restore(&stripped), text);
         assert_eq!(
-            strip_osc(&build_link_prefix_suffix("just a string here")),
-            (String::default(), Some(String::from("just a string here")))
-        );
-        assert_eq!(
-            strip_osc(&build_link_prefix_suffix("/etc/rc.conf")),
-            (String::default(), Some(String::from("/etc/rc.conf")))
+            links.restore("see A or si"),
+            format!("see {} or {}", link("a.com", "A"), link("b.com", "si"))
         );
         assert_eq!(
-            strip_osc(
-                &build_link_prefix_suffix("https://gitlab.com/finestructure/swiftpackageindex-builder/-/pipelines/1054655982")
-            ),
-            (String::default(), Some(String::from("https://gitlab.com/finestructure/swiftpackageindex-builder/-/pipelines/1054655982")))
+            links.restore("see A \nor\nsite \nB"),
+            format!(
+                "see {} \nor\n{}\n{}",
+                link("a.com", "A"),
+                link("b.com", "site "),
+                link("b.com", "B")
+            )
         );
 
-        #[cfg(feature = "ansi")]
-        fn build_link_prefix_suffix(url: &str) -> String {
-            // https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
-            let osc8 = "\x1b]8;;";
-            let st = "\x1b\\";
-            format!("{osc8}{url}{st}")
-        }
+        let text = format!("{}\x1b]8;;\x07", "\x1b]8;id=1;a.com\x07A");
+        let (stripped, links) = Hyperlinks::split(&text);
+        assert_eq!(stripped, "A");
+        assert_eq!(links.restore("A"), "\x1b]8;id=1;a.com\x1b\\A\x1b]8;;\x1b\\");
+    }
+
+    #[test]
+    #[cfg(feature = "ansi")]
+    fn cut_hyperlink_test() {
+        let text = "\x1b]8;;a.com\x1b\\Hello World\x1b]8;;\x1b\\!";
+        assert_eq!(cut_str(text, 3), "\x1b]8;;a.com\x1b\\Hel\x1b]8;;\x1b\\");
+        assert_eq!(cut_str(text, 20), text);
     }
 }
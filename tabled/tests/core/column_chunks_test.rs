@@ -0,0 +1,248 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::{
+        object::{Cell, Columns},
+        Alignment, Modify, Panel, Span, Style,
+    },
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::static_table;
+
+fn chunks(table: &Table, width: usize) -> Vec<String> {
+    table.split_columns(width).collect()
+}
+
+fn matrix() -> Table {
+    let mut table = Matrix::new(2, 4).to_table();
+    table.with(Style::psql());
+    table
+}
+
+#[test]
+fn split_columns_empty() {
+    assert!(chunks(&Table::default(), 30).is_empty());
+}
+
+#[test]
+fn split_columns_single_column() {
+    let table = Matrix::new(2, 0).to_table();
+
+    assert_eq!(
+        chunks(&table, 3),
+        [static_table!(
+            "+---+"
+            "| N |"
+            "+---+"
+            "| 0 |"
+            "+---+"
+            "| 1 |"
+            "+---+"
+        )]
+    );
+}
+
+#[test]
+fn split_columns_fits() {
+    assert_eq!(
+        chunks(&matrix(), 80),
+        [static_table!(
+            " N | column 0 | column 1 | column 2 | column 3 "
+            "---+----------+----------+----------+----------"
+            " 0 |   0-0    |   0-1    |   0-2    |   0-3    "
+            " 1 |   1-0    |   1-1    |   1-2    |   1-3    "
+        )]
+    );
+}
+
+#[test]
+fn split_columns_repeats_first_column() {
+    assert_eq!(
+        chunks(&matrix(), 30),
+        [
+            static_table!(
+                " N | column 0 | column 1 "
+                "---+----------+----------"
+                " 0 |   0-0    |   0-1    "
+                " 1 |   1-0    |   1-1    "
+            ),
+            static_table!(
+                " N | column 2 | column 3 "
+                "---+----------+----------"
+                " 0 |   0-2    |   0-3    "
+                " 1 |   1-2    |   1-3    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_columns_without_keys() {
+    assert_eq!(
+        matrix().split_columns(25).keys([]).collect::<Vec<_>>(),
+        [
+            static_table!(
+                " N | column 0 | column 1 "
+                "---+----------+----------"
+                " 0 |   0-0    |   0-1    "
+                " 1 |   1-0    |   1-1    "
+            ),
+            static_table!(
+                " column 2 | column 3 "
+                "----------+----------"
+                "   0-2    |   0-3    "
+                "   1-2    |   1-3    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_columns_multiple_keys() {
+    assert_eq!(
+        matrix().split_columns(30).keys([0, 1]).collect::<Vec<_>>(),
+        [
+            static_table!(
+                " N | column 0 | column 1 "
+                "---+----------+----------"
+                " 0 |   0-0    |   0-1    "
+                " 1 |   1-0    |   1-1    "
+            ),
+            static_table!(
+                " N | column 0 | column 2 "
+                "---+----------+----------"
+                " 0 |   0-0    |   0-2    "
+                " 1 |   1-0    |   1-2    "
+            ),
+            static_table!(
+                " N | column 0 | column 3 "
+                "---+----------+----------"
+                " 0 |   0-0    |   0-3    "
+                " 1 |   1-0    |   1-3    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_columns_keeps_order_of_key_column() {
+    assert_eq!(
+        matrix().split_columns(30).keys([4]).collect::<Vec<_>>(),
+        [
+            static_table!(
+                " N | column 0 | column 3 "
+                "---+----------+----------"
+                " 0 |   0-0    |   0-3    "
+                " 1 |   1-0    |   1-3    "
+            ),
+            static_table!(
+                " column 1 | column 3 "
+                "----------+----------"
+                "   0-1    |   0-3    "
+                "   1-1    |   1-3    "
+            ),
+            static_table!(
+                " column 2 | column 3 "
+                "----------+----------"
+                "   0-2    |   0-3    "
+                "   1-2    |   1-3    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_columns_too_narrow() {
+    assert_eq!(
+        chunks(&matrix(), 1),
+        [
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 0 |   0-0    "
+                " 1 |   1-0    "
+            ),
+            static_table!(
+                " N | column 1 "
+                "---+----------"
+                " 0 |   0-1    "
+                " 1 |   1-1    "
+            ),
+            static_table!(
+                " N | column 2 "
+                "---+----------"
+                " 0 |   0-2    "
+                " 1 |   1-2    "
+            ),
+            static_table!(
+                " N | column 3 "
+                "---+----------"
+                " 0 |   0-3    "
+                " 1 |   1-3    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_columns_with_panel() {
+    let mut table = Matrix::new(2, 4).to_table();
+    table.with(Panel::header("Matrix")).with(Style::modern());
+
+    assert_eq!(
+        table.split_columns(30).keys([]).collect::<Vec<_>>(),
+        [
+            static_table!(
+                "┌───┬──────────┬──────────┐"
+                "│         Matrix          │"
+                "├───┼──────────┼──────────┤"
+                "│ N │ column 0 │ column 1 │"
+                "├───┼──────────┼──────────┤"
+                "│ 0 │   0-0    │   0-1    │"
+                "├───┼──────────┼──────────┤"
+                "│ 1 │   1-0    │   1-1    │"
+                "└───┴──────────┴──────────┘"
+            ),
+            static_table!(
+                "┌──────────┬──────────┐"
+                "│       Matrix        │"
+                "├──────────┼──────────┤"
+                "│ column 2 │ column 3 │"
+                "├──────────┼──────────┤"
+                "│   0-2    │   0-3    │"
+                "├──────────┼──────────┤"
+                "│   1-2    │   1-3    │"
+                "└──────────┴──────────┘"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_columns_keeps_cell_settings() {
+    let mut table = matrix();
+    table
+        .with(Modify::new(Columns::new(..)).with(Alignment::left()))
+        .with(Modify::new(Columns::single(3)).with(Alignment::right()))
+        .with(Modify::new(Cell::new(1, 3)).with(Span::column(2)));
+
+    assert_eq!(
+        chunks(&table, 30),
+        [
+            static_table!(
+                " N | column 0 | column 1 "
+                "---+----------+----------"
+                " 0 | 0-0      | 0-1      "
+                " 1 | 1-0      | 1-1      "
+            ),
+            static_table!(
+                " N | column 2 | column 3 "
+                "---+----------+----------"
+                " 0 |                 0-2 "
+                " 1 |      1-2 | 1-3      "
+            ),
+        ]
+    );
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{Cell, Columns, Rows},
+        Disable, Extract, Format, Modify, Padding, Panel, Span, Style,
+    },
+    Table,
+};
+
+use crate::matrix::Matrix;
+
+#[test]
+fn csv_empty() {
+    assert_eq!(Table::default().to_csv(), "");
+    assert_eq!(Table::default().to_tsv(), "");
+}
+
+#[test]
+fn csv_basic() {
+    let table = Matrix::new(2, 2).to_table();
+
+    assert_eq!(
+        table.to_csv(),
+        "N,column 0,column 1\n0,0-0,0-1\n1,1-0,1-1\n"
+    );
+}
+
+#[test]
+fn csv_ignores_style_and_padding() {
+    let mut table = Matrix::new(1, 2).to_table();
+    table.with(Style::modern()).with(Padding::new(3, 3, 1, 1));
+
+    assert_eq!(table.to_csv(), "N,column 0,column 1\n0,0-0,0-1\n");
+}
+
+#[test]
+fn csv_quoting() {
+    let mut builder = Builder::default();
+    builder.push_record(["plain", "with,comma", "with \"quote\""]);
+    builder.push_record(["multi\nline", "carriage\r", " spaces "]);
+    let table = builder.build();
+
+    assert_eq!(
+        table.to_csv(),
+        concat!(
+            "plain,\"with,comma\",\"with \"\"quote\"\"\"\n",
+            "\"multi\nline\",\"carriage\r\", spaces \n",
+        )
+    );
+}
+
+#[test]
+fn tsv_quoting() {
+    let mut builder = Builder::default();
+    builder.push_record(["with,comma", "with\ttab"]);
+    let table = builder.build();
+
+    assert_eq!(table.to_tsv(), "with,comma\t\"with\ttab\"\n");
+}
+
+#[test]
+fn csv_custom_delimiter() {
+    let table = Matrix::new(1, 1).to_table();
+
+    assert_eq!(
+        table.csv().delimiter('|').to_string(),
+        "N|column 0\n0|0-0\n"
+    );
+}
+
+#[test]
+fn csv_after_settings() {
+    let mut table = Matrix::new(3, 3).to_table();
+    table
+        .with(Disable::column(Columns::first()))
+        .with(Extract::rows(1..3))
+        .with(Modify::new(Columns::single(1)).with(Format::content(|s| format!("<{s}>"))));
+
+    assert_eq!(table.to_csv(), "0-0,<0-1>,0-2\n1-0,<1-1>,1-2\n");
+}
+
+#[test]
+fn csv_spans() {
+    let mut table = Matrix::new(2, 2).to_table();
+    table
+        .with(Panel::header("Title"))
+        .with(Modify::new(Cell::new(2, 1)).with(Span::column(2)));
+
+    assert_eq!(
+        table.to_csv(),
+        "Title,,\nN,column 0,column 1\n0,0-0,\n1,1-0,1-1\n"
+    );
+}
+
+#[test]
+fn csv_write() {
+    let mut table = Matrix::new(1, 2).to_table();
+    table.with(Disable::row(Rows::first()));
+
+    let mut buf = Vec::new();
+    table.csv().write(&mut buf).unwrap();
+
+    assert_eq!(buf, b"0,0-0,0-1\n");
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn csv_strip_ansi() {
+    let mut builder = Builder::default();
+    builder.push_record(["\u{1b}[31mred\u{1b}[39m", "\u{1b}[1mbold, text\u{1b}[22m"]);
+    let table = builder.build();
+
+    assert_eq!(
+        table.to_csv(),
+        "\u{1b}[31mred\u{1b}[39m,\"\u{1b}[1mbold, text\u{1b}[22m\"\n"
+    );
+    assert_eq!(
+        table.csv().strip_ansi(true).to_string(),
+        "red,\"bold, text\"\n"
+    );
+}
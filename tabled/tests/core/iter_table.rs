@@ -212,3 +212,165 @@ test_table!(
     "| 0   | 0   | 0   | 0   |"
     "+-----+-----+-----+-----+"
 );
+
+mod stream {
+    use std::io;
+
+    use tabled::settings::{Margin, Style};
+
+    use super::*;
+
+    fn stream_to_string<F>(table: IterTable<Vec<Vec<String>>>, f: F) -> String
+    where
+        F: FnOnce(&mut tabled::tables::IterTableStream<&mut Vec<u8>>),
+    {
+        let mut buf = Vec::new();
+        let mut stream = table.stream(&mut buf).unwrap();
+        f(&mut stream);
+        stream.finish().unwrap();
+
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn stream_is_same_as_build() {
+        let data = Matrix::with_no_frame(3, 3).to_vec();
+        let styles = [
+            Style::ascii().into(),
+            Style::modern().into(),
+            Style::psql().into(),
+            Style::blank().into(),
+            Style::extended().into(),
+        ];
+
+        for style in styles {
+            let style: tabled::settings::themes::Theme = style;
+            let table = IterTable::new(data.clone()).with(style);
+            let expected = table.clone().to_string() + "\n";
+            assert_eq!(stream_to_string(table, |_| {}), expected);
+        }
+    }
+
+    #[test]
+    fn stream_push_rows() {
+        let table = IterTable::new(vec![vec![String::from("id"), String::from("name")]])
+            .with(Style::modern());
+
+        let output = stream_to_string(table, |stream| {
+            stream.push_row(["1", "Tom"]).unwrap();
+            stream.push_row(["2", "Anna", "ignored"]).unwrap();
+            stream.push_row(["3"]).unwrap();
+        });
+
+        assert_eq!(
+            output,
+            concat!(
+                "┌────┬──────┐\n",
+                "│ id │ name │\n",
+                "├────┼──────┤\n",
+                "│ 1  │ Tom  │\n",
+                "├────┼──────┤\n",
+                "│ 2  │ Anna │\n",
+                "├────┼──────┤\n",
+                "│ 3  │      │\n",
+                "└────┴──────┘\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn stream_truncate_and_wrap() {
+        let header = vec![vec![String::from("message")]];
+
+        let output = stream_to_string(IterTable::new(header.clone()), |stream| {
+            stream.push_row(["Hello World"]).unwrap();
+        });
+
+        assert_eq!(
+            output,
+            concat!(
+                "+---------+\n",
+                "| message |\n",
+                "+---------+\n",
+                "| Hello W |\n",
+                "+---------+\n",
+            ),
+        );
+
+        let output = stream_to_string(IterTable::new(header).wrap(true), |stream| {
+            stream.push_row(["Hello World"]).unwrap();
+        });
+
+        assert_eq!(
+            output,
+            concat!(
+                "+---------+\n",
+                "| message |\n",
+                "+---------+\n",
+                "| Hello W |\n",
+                "| orld    |\n",
+                "+---------+\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn stream_fixed_width_and_rows_limit() {
+        let table = IterTable::new(Matrix::with_no_frame(3, 2).to_vec())
+            .width(2)
+            .columns(2)
+            .rows(2)
+            .with(Margin::new(1, 0, 1, 1));
+
+        let output = stream_to_string(table, |stream| {
+            stream.push_row(["x", "y"]).unwrap();
+        });
+
+        assert_eq!(
+            output,
+            concat!(
+                "            \n",
+                " +----+----+\n",
+                " | 0- | 0- |\n",
+                " +----+----+\n",
+                " | 1- | 1- |\n",
+                " +----+----+\n",
+                "            \n",
+            ),
+        );
+    }
+
+    #[test]
+    fn stream_flushes_each_row_and_closes_on_drop() {
+        #[derive(Default)]
+        struct Writer {
+            buf: Vec<u8>,
+            flushed: Vec<usize>,
+        }
+
+        impl io::Write for &mut Writer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.buf.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                self.flushed.push(self.buf.len());
+                Ok(())
+            }
+        }
+
+        let mut writer = Writer::default();
+
+        {
+            let mut stream = IterTable::new([["a"]]).stream(&mut writer).unwrap();
+            stream.push_row(["b"]).unwrap();
+        }
+
+        assert_eq!(
+            String::from_utf8(writer.buf).unwrap(),
+            "+---+\n| a |\n+---+\n| b |\n+---+\n",
+        );
+        assert_eq!(writer.flushed, [12, 24, 30]);
+    }
+}
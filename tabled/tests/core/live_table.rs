@@ -0,0 +1,169 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::Style,
+    tables::{IterTable, LiveTable},
+};
+
+/// A virtual terminal which supports sequences used by [`LiveTable`].
+#[derive(Debug, Default)]
+struct Terminal {
+    screen: Vec<Vec<char>>,
+    row: usize,
+    col: usize,
+}
+
+impl Terminal {
+    fn feed(&mut self, output: &str) {
+        let mut chars = output.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\n' => {
+                    self.row += 1;
+                    self.col = 0;
+                }
+                '\r' => self.col = 0,
+                '\u{1b}' => {
+                    assert_eq!(chars.next(), Some('['));
+
+                    let mut arg = String::new();
+                    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                        arg.push(c);
+                    }
+
+                    let n = arg.parse::<usize>().unwrap_or(1);
+                    let col = self.col;
+                    match chars.next() {
+                        Some('A') => self.row -= n,
+                        Some('B') => self.row += n,
+                        Some('G') => self.col = n - 1,
+                        Some('K') => self.line().truncate(col),
+                        Some('J') => {
+                            self.line().truncate(col);
+                            self.screen.truncate(self.row + 1);
+                        }
+                        c => panic!("unexpected sequence {:?}", c),
+                    }
+                }
+                c => {
+                    let col = self.col;
+                    let line = self.line();
+                    if line.len() <= col {
+                        line.resize(col + 1, ' ');
+                    }
+
+                    line[col] = c;
+                    self.col += 1;
+                }
+            }
+        }
+    }
+
+    fn line(&mut self) -> &mut Vec<char> {
+        if self.screen.len() <= self.row {
+            self.screen.resize(self.row + 1, Vec::new());
+        }
+
+        &mut self.screen[self.row]
+    }
+
+    fn screen(&self) -> String {
+        let mut lines = self
+            .screen
+            .iter()
+            .map(|line| line.iter().collect::<String>())
+            .collect::<Vec<_>>();
+
+        while lines.last().is_some_and(String::is_empty) {
+            lines.pop();
+        }
+
+        lines.join("\n")
+    }
+}
+
+fn render<I>(live: &mut LiveTable<Vec<u8>>, table: IterTable<I>) -> String
+where
+    I: tabled::grid::records::IntoRecords,
+    I::Cell: AsRef<str>,
+{
+    let start = live.get_ref().len();
+    live.render(table).unwrap();
+    String::from_utf8(live.get_ref()[start..].to_vec()).unwrap()
+}
+
+fn frame(rows: &[[&str; 2]]) -> IterTable<Vec<Vec<String>>> {
+    let rows = rows
+        .iter()
+        .map(|row| row.iter().map(|s| s.to_string()).collect())
+        .collect();
+
+    IterTable::new(rows).with(Style::modern())
+}
+
+#[test]
+fn live_table_updates_changed_cells() {
+    let mut live = LiveTable::new(Vec::new());
+    let mut term = Terminal::default();
+
+    let first = frame(&[["cpu", "10%"], ["mem", "512M"]]);
+    let expected = first.clone().to_string();
+    term.feed(&render(&mut live, first));
+    assert_eq!(term.screen(), expected);
+    assert_eq!((term.row, term.col), (5, 0));
+
+    let second = frame(&[["cpu", "15%"], ["mem", "512M"]]);
+    let expected = second.clone().to_string();
+    let output = render(&mut live, second);
+    term.feed(&output);
+    assert_eq!(term.screen(), expected);
+    assert_eq!((term.row, term.col), (5, 0));
+    assert_eq!(output, "\u{1b}[4A\u{1b}[10G5\r\u{1b}[4B");
+
+    let third = frame(&[["cpu", "15%"], ["mem", "512M"]]);
+    assert_eq!(render(&mut live, third), "");
+}
+
+#[test]
+fn live_table_changes_size() {
+    let mut live = LiveTable::new(Vec::new());
+    let mut term = Terminal::default();
+
+    let frames = [
+        frame(&[["cpu", "10%"]]),
+        frame(&[["cpu", "10%"], ["mem", "512M"], ["disk", "1T"]]),
+        frame(&[["cpu", "100%"], ["mem", "2G"]]),
+        frame(&[["cpu", "1%"]]),
+        frame(&[["network", "connected"]]),
+    ];
+
+    for table in frames {
+        let expected = table.clone().to_string();
+        term.feed(&render(&mut live, table));
+
+        assert_eq!(term.screen(), expected);
+        assert_eq!((term.row, term.col), (expected.lines().count(), 0));
+    }
+}
+
+#[test]
+fn live_table_reset() {
+    let mut live = LiveTable::new(Vec::new());
+    let mut term = Terminal::default();
+
+    term.feed(&render(&mut live, frame(&[["a", "b"]])));
+    live.reset();
+    term.feed(&render(&mut live, frame(&[["c", "d"]])));
+
+    assert_eq!(
+        term.screen(),
+        concat!(
+            "┌───┬───┐\n",
+            "│ a │ b │\n",
+            "└───┴───┘\n",
+            "┌───┬───┐\n",
+            "│ c │ d │\n",
+            "└───┴───┘",
+        ),
+    );
+}
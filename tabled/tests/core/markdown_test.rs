@@ -0,0 +1,128 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{Cell, Columns, Rows},
+        Alignment, Disable, Modify, Panel, Span, Style,
+    },
+    Table,
+};
+
+use crate::matrix::Matrix;
+
+#[test]
+fn markdown_empty() {
+    assert_eq!(Table::default().to_markdown(), "");
+}
+
+#[test]
+fn markdown_header_only() {
+    let table = Matrix::new(0, 2).to_table();
+
+    assert_eq!(
+        table.to_markdown(),
+        "| N | column 0 | column 1 |\n| :---: | :---: | :---: |\n"
+    );
+}
+
+#[test]
+fn markdown_basic() {
+    let mut table = Matrix::new(2, 2).to_table();
+    table.with(Style::rounded());
+
+    assert_eq!(
+        table.to_markdown(),
+        concat!(
+            "| N | column 0 | column 1 |\n",
+            "| :---: | :---: | :---: |\n",
+            "| 0 | 0-0 | 0-1 |\n",
+            "| 1 | 1-0 | 1-1 |\n",
+        )
+    );
+}
+
+#[test]
+fn markdown_alignment() {
+    let mut table = Matrix::new(2, 2).to_table();
+    table
+        .with(Modify::new(Columns::single(1)).with(Alignment::center()))
+        .with(Modify::new(Columns::single(0)).with(Alignment::left()))
+        .with(Modify::new(Columns::single(2)).with(Alignment::right()))
+        .with(Modify::new(Rows::first()).with(Alignment::right()));
+
+    assert_eq!(
+        table.to_markdown(),
+        concat!(
+            "| N | column 0 | column 1 |\n",
+            "| :--- | :---: | ---: |\n",
+            "| 0 | 0-0 | 0-1 |\n",
+            "| 1 | 1-0 | 1-1 |\n",
+        )
+    );
+}
+
+#[test]
+fn markdown_escaping() {
+    let mut builder = Builder::default();
+    builder.push_record(["a|b", "text"]);
+    builder.push_record(["multi\nline", "windows\r\nline"]);
+    builder.push_record(["", "||"]);
+    let table = builder.build();
+
+    assert_eq!(
+        table.to_markdown(),
+        concat!(
+            "| a\\|b | text |\n",
+            "| :--- | :--- |\n",
+            "| multi<br>line | windows<br>line |\n",
+            "|  | \\|\\| |\n",
+        )
+    );
+}
+
+#[test]
+fn markdown_after_settings() {
+    let mut table = Matrix::new(2, 2).to_table();
+    table.with(Disable::column(Columns::first()));
+
+    assert_eq!(
+        table.to_markdown(),
+        concat!(
+            "| column 0 | column 1 |\n",
+            "| :---: | :---: |\n",
+            "| 0-0 | 0-1 |\n",
+            "| 1-0 | 1-1 |\n",
+        )
+    );
+}
+
+#[test]
+fn markdown_spans() {
+    let mut table = Matrix::new(2, 2).to_table();
+    table
+        .with(Panel::header("Title"))
+        .with(Modify::new(Cell::new(2, 1)).with(Span::column(2)));
+
+    assert_eq!(
+        table.to_markdown(),
+        concat!(
+            "| Title |  |  |\n",
+            "| :---: | :---: | :---: |\n",
+            "| N | column 0 | column 1 |\n",
+            "| 0 | 0-0 |  |\n",
+            "| 1 | 1-0 | 1-1 |\n",
+        )
+    );
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn markdown_strips_ansi() {
+    let mut builder = Builder::default();
+    builder.push_record(["\u{1b}[31mred\u{1b}[39m"]);
+    builder.push_record(["\u{1b}[1mbold|text\u{1b}[22m"]);
+    let table = builder.build();
+
+    assert_eq!(table.to_markdown(), "| red |\n| :--- |\n| bold\\|text |\n");
+}
@@ -1,7 +1,12 @@
 mod builder_test;
+mod column_chunks_test;
 mod compact_table;
+mod csv_test;
 mod extended_table_test;
 mod index_test;
 mod iter_table;
+mod live_table;
+mod markdown_test;
+mod pages_test;
 mod pool_table;
 mod table_test;
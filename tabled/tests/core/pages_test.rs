@@ -0,0 +1,260 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::{object::Cell, peaker::PriorityMax, Height, Modify, Panel, Span, Style},
+    tables::PageLimit,
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::static_table;
+
+fn pages(table: &Table, limit: PageLimit) -> Vec<String> {
+    table.paginate(limit).collect()
+}
+
+#[test]
+fn paginate_empty() {
+    assert!(pages(&Table::default(), PageLimit::Rows(2)).is_empty());
+}
+
+#[test]
+fn paginate_header_only() {
+    let table = Matrix::new(0, 2).to_table();
+
+    assert_eq!(
+        pages(&table, PageLimit::Rows(2)),
+        [static_table!(
+            "+---+----------+----------+"
+            "| N | column 0 | column 1 |"
+            "+---+----------+----------+"
+        )]
+    );
+}
+
+#[test]
+fn paginate_by_rows() {
+    let mut table = Matrix::new(5, 2).to_table();
+    table.with(Style::psql());
+
+    assert_eq!(
+        pages(&table, PageLimit::Rows(2)),
+        [
+            static_table!(
+                " N | column 0 | column 1 "
+                "---+----------+----------"
+                " 0 |   0-0    |   0-1    "
+                " 1 |   1-0    |   1-1    "
+            ),
+            static_table!(
+                " N | column 0 | column 1 "
+                "---+----------+----------"
+                " 2 |   2-0    |   2-1    "
+                " 3 |   3-0    |   3-1    "
+            ),
+            static_table!(
+                " N | column 0 | column 1 "
+                "---+----------+----------"
+                " 4 |   4-0    |   4-1    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_zero_rows_is_one_row() {
+    let mut table = Matrix::new(2, 1).to_table();
+    table.with(Style::psql());
+
+    assert_eq!(
+        pages(&table, PageLimit::Rows(0)),
+        [
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 0 |   0-0    "
+            ),
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 1 |   1-0    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_without_header() {
+    let mut table = Matrix::new(2, 1).to_table();
+    table.with(Style::psql());
+
+    assert_eq!(
+        table
+            .paginate(PageLimit::Rows(2))
+            .header(0)
+            .collect::<Vec<_>>(),
+        [
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 0 |   0-0    "
+            ),
+            static_table!(
+                " 1 |   1-0    "
+                "---+----------"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_repeats_panel_header() {
+    let mut table = Matrix::new(3, 1).to_table();
+    table
+        .with(Panel::header("Matrix"))
+        .with(Panel::footer("end"))
+        .with(Style::modern());
+
+    assert_eq!(
+        pages(&table, PageLimit::Rows(2)),
+        [
+            static_table!(
+                "┌───┬──────────┐"
+                "│    Matrix    │"
+                "├───┼──────────┤"
+                "│ N │ column 0 │"
+                "├───┼──────────┤"
+                "│ 0 │   0-0    │"
+                "├───┼──────────┤"
+                "│ 1 │   1-0    │"
+                "└───┴──────────┘"
+            ),
+            static_table!(
+                "┌───┬──────────┐"
+                "│    Matrix    │"
+                "├───┼──────────┤"
+                "│ N │ column 0 │"
+                "├───┼──────────┤"
+                "│ 2 │   2-0    │"
+                "├───┼──────────┤"
+                "│     end      │"
+                "└───┴──────────┘"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_by_height() {
+    let mut table = Matrix::new(4, 1).to_table();
+    table.with(Style::ascii());
+
+    let list = pages(&table, PageLimit::Height(8));
+    assert_eq!(
+        list,
+        [
+            static_table!(
+                "+---+----------+"
+                "| N | column 0 |"
+                "+---+----------+"
+                "| 0 |   0-0    |"
+                "+---+----------+"
+                "| 1 |   1-0    |"
+                "+---+----------+"
+            ),
+            static_table!(
+                "+---+----------+"
+                "| N | column 0 |"
+                "+---+----------+"
+                "| 2 |   2-0    |"
+                "+---+----------+"
+                "| 3 |   3-0    |"
+                "+---+----------+"
+            ),
+        ]
+    );
+
+    for page in list {
+        assert!(page.lines().count() <= 8);
+    }
+}
+
+#[test]
+fn paginate_by_height_too_small() {
+    let mut table = Matrix::new(2, 1).to_table();
+    table.with(Style::psql());
+
+    assert_eq!(
+        pages(&table, PageLimit::Height(1)),
+        [
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 0 |   0-0    "
+            ),
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 1 |   1-0    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_keeps_widths_and_heights() {
+    let mut table = Matrix::new(3, 1)
+        .insert((3, 1), "a long\nvalue\nhere")
+        .to_table();
+    table
+        .with(Style::psql())
+        .with(Height::limit(6).priority(PriorityMax));
+
+    assert_eq!(
+        pages(&table, PageLimit::Rows(2)),
+        [
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 0 |   0-0    "
+                " 1 |   1-0    "
+            ),
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 2 |  a long  "
+                "   |  value   "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_keeps_row_spans_together() {
+    let mut table = Matrix::new(4, 1).to_table();
+    table
+        .with(Modify::new(Cell::new(2, 1)).with(Span::row(2)))
+        .with(Style::psql());
+
+    assert_eq!(
+        pages(&table, PageLimit::Rows(2)),
+        [
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 0 |   0-0    "
+            ),
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 1 |   1-0    "
+                " 2 |          "
+            ),
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 3 |   3-0    "
+            ),
+        ]
+    );
+}
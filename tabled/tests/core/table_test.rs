@@ -714,6 +714,7 @@ mod derived {
         "+-----------------+----------+----------+----------+---------+"
     );
 
+    #[cfg(not(feature = "grapheme"))]
     test_table!(
         table_emojie_multiline,
         Table::new({
@@ -750,6 +751,44 @@ mod derived {
         "| Keep it simple                     | Unknown         | 🍳                            | 100    |"
         "+------------------------------------+-----------------+-------------------------------+--------+"
     );
+
+    #[cfg(feature = "grapheme")]
+    test_table!(
+        table_emojie_multiline_grapheme,
+        Table::new({
+            #[derive(Tabled)]
+            struct Article {
+                name: &'static str,
+                author: &'static str,
+                text: &'static str,
+                rating: usize,
+            }
+
+            vec![
+                Article {
+                    name: "Rebase vs Merge commit in depth 👋",
+                    author: "Rose Kuphal DVM",
+                    text: "A multiline\n text with 🤯 😳 🥵 🥶\n a bunch of emojies ☄️ 💥 🔥 🌪",
+                    rating: 43,
+                },
+                Article {
+                    name: "Keep it simple",
+                    author: "Unknown",
+                    text: "🍳",
+                    rating: 100,
+                },
+            ]
+        }),
+        "+------------------------------------+-----------------+--------------------------------+--------+"
+        "| name                               | author          | text                           | rating |"
+        "+------------------------------------+-----------------+--------------------------------+--------+"
+        "| Rebase vs Merge commit in depth 👋 | Rose Kuphal DVM | A multiline                    | 43     |"
+        "|                                    |                 |  text with 🤯 😳 🥵 🥶         |        |"
+        "|                                    |                 |  a bunch of emojies ☄\u{fe0f} 💥 🔥 🌪 |        |"
+        "+------------------------------------+-----------------+--------------------------------+--------+"
+        "| Keep it simple                     | Unknown         | 🍳                             | 100    |"
+        "+------------------------------------+-----------------+--------------------------------+--------+"
+    );
 }
 
 #[cfg(feature = "ansi")]
@@ -907,3 +946,39 @@ test_table!(
     " ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒"
     " ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒"
 );
+
+test_table!(
+    table_from_map_test,
+    Table::from_map(std::collections::BTreeMap::from([("b", 2.5), ("a", 10.0)])),
+    "+-----+-------+"
+    "| Key | Value |"
+    "+-----+-------+"
+    "| a   | 10    |"
+    "+-----+-------+"
+    "| b   | 2.5   |"
+    "+-----+-------+"
+);
+
+test_table!(
+    table_from_map_sorted_test,
+    Table::from_map_sorted(std::collections::HashMap::from([(3, "c"), (1, "a"), (2, "b")])),
+    "+-----+-------+"
+    "| Key | Value |"
+    "+-----+-------+"
+    "| 1   | a     |"
+    "+-----+-------+"
+    "| 2   | b     |"
+    "+-----+-------+"
+    "| 3   | c     |"
+    "+-----+-------+"
+);
+
+test_table!(
+    table_key_value_test,
+    Table::new([tabled::KeyValue::new(std::net::Ipv4Addr::LOCALHOST, 'x')]),
+    "+-----------+-------+"
+    "| Key       | Value |"
+    "+-----------+-------+"
+    "| 127.0.0.1 | x     |"
+    "+-----------+-------+"
+);
@@ -1630,10 +1630,1136 @@ fn test_enum_format_1() {
     }
 }
 
+#[test]
+fn test_values_struct() {
+    use tabled::{TabledValue, TabledValues};
+
+    #[derive(Tabled, TabledValues)]
+    struct Release {
+        #[tabled(order = 1)]
+        version: String,
+        downloads: u64,
+        #[tabled(skip)]
+        #[allow(dead_code)]
+        checksum: String,
+        yanked: bool,
+        #[tabled(datetime)]
+        date: &'static str,
+        #[tabled(format("{}%", self.coverage))]
+        coverage: f32,
+        #[tabled(inline)]
+        stats: (f64, i8),
+    }
+
+    let release = Release {
+        version: String::from("0.1.0"),
+        downloads: 100,
+        checksum: String::from("ff00ff"),
+        yanked: false,
+        date: "2024-07-23 10:00:00",
+        coverage: 87.5,
+        stats: (0.25, -1),
+    };
+
+    assert_eq!(
+        release.values(),
+        [
+            TabledValue::Int(100),
+            TabledValue::String("0.1.0".into()),
+            TabledValue::Bool(false),
+            TabledValue::DateTime("2024-07-23 10:00:00".into()),
+            TabledValue::String("87.5%".into()),
+            TabledValue::Float(0.25),
+            TabledValue::Int(-1),
+        ]
+    );
+    assert_eq!(release.values().len(), release.fields().len());
+}
+
+#[test]
+fn test_values_enum() {
+    use tabled::{TabledValue, TabledValues};
+
+    #[derive(Tabled, TabledValues)]
+    enum Shape {
+        Point,
+        #[tabled(inline("circle."))]
+        Circle {
+            radius: f32,
+            #[tabled(skip)]
+            #[allow(dead_code)]
+            filled: bool,
+        },
+        #[tabled(inline)]
+        Square(usize, &'static str),
+        #[tabled(skip)]
+        #[allow(dead_code)]
+        Line,
+    }
+
+    assert_eq!(
+        Shape::Point.values(),
+        [
+            TabledValue::String("+".into()),
+            TabledValue::Null,
+            TabledValue::Null,
+            TabledValue::Null,
+        ]
+    );
+    assert_eq!(
+        Shape::Circle {
+            radius: 1.5,
+            filled: true
+        }
+        .values(),
+        [
+            TabledValue::Null,
+            TabledValue::Float(1.5),
+            TabledValue::Null,
+            TabledValue::Null,
+        ]
+    );
+    assert_eq!(
+        Shape::Square(2, "blue").values(),
+        [
+            TabledValue::Null,
+            TabledValue::Null,
+            TabledValue::Int(2),
+            TabledValue::String("blue".into()),
+        ]
+    );
+    assert_eq!(Shape::Line.values(), vec![TabledValue::Null; 4]);
+}
+
+#[test]
+fn test_values_enum_inline() {
+    use tabled::{TabledValue, TabledValues};
+
+    #[derive(Tabled, TabledValues)]
+    #[tabled(inline("Status"))]
+    enum Status {
+        #[tabled(rename = "ok")]
+        Ok,
+        #[allow(dead_code)]
+        Failed(String),
+        #[tabled(skip)]
+        Unknown,
+    }
+
+    assert_eq!(Status::Ok.values(), [TabledValue::String("ok".into())]);
+    assert_eq!(Status::Unknown.values(), [TabledValue::Null]);
+}
+
+#[test]
+fn test_column_settings() {
+    use tabled::{settings::Style, Table};
+
+    #[derive(Tabled)]
+    struct Crate {
+        #[tabled(order = 2, align = "right")]
+        downloads: usize,
+        #[tabled(max_width = 6)]
+        name: &'static str,
+        #[tabled(align = "center")]
+        license: &'static str,
+    }
+
+    let data = [
+        Crate {
+            downloads: 1024,
+            name: "tabled_derive",
+            license: "MIT",
+        },
+        Crate {
+            downloads: 7,
+            name: "papergrid",
+            license: "Apache-2.0",
+        },
+    ];
+
+    let mut table = Table::new(data);
+    table.with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            " name   |  license   | downloads \n",
+            "--------+------------+-----------\n",
+            " tabled |    MIT     |      1024 \n",
+            " paperg | Apache-2.0 |         7 ",
+        )
+    );
+}
+
+#[test]
+fn test_column_settings_inline() {
+    use tabled::{
+        grid::config::AlignmentHorizontal,
+        settings::{Color, ColumnSettings},
+        Table,
+    };
+
+    #[derive(Tabled)]
+    struct User {
+        #[tabled(color = "bright_red")]
+        name: &'static str,
+        #[tabled(inline)]
+        address: Address,
+        #[tabled(inline("status."))]
+        status: Status,
+    }
+
+    #[derive(Tabled)]
+    struct Address {
+        street: &'static str,
+        #[tabled(align = "right")]
+        number: usize,
+    }
+
+    #[derive(Tabled)]
+    enum Status {
+        #[tabled(color = "bg_green")]
+        Active,
+        #[tabled(inline)]
+        #[allow(dead_code)]
+        Blocked(#[tabled(rename = "reason", color = "bold")] &'static str),
+    }
+
+    assert_eq!(
+        User::column_settings(),
+        [
+            ColumnSettings::new().color(Color::FG_BRIGHT_RED),
+            ColumnSettings::new(),
+            ColumnSettings::new().alignment(AlignmentHorizontal::Right),
+            ColumnSettings::new().color(Color::BG_GREEN),
+            ColumnSettings::new().color(Color::BOLD),
+        ]
+    );
+    assert_eq!(<(User, usize)>::column_settings().len(), 6);
+
+    let user = User {
+        name: "Ann",
+        address: Address {
+            street: "Main",
+            number: 7,
+        },
+        status: Status::Active,
+    };
+
+    let table = Table::new([user]);
+
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            "+------+--------+--------+---------------+---------------+\n",
+            "| \u{1b}[91mname\u{1b}[39m | street | number | \u{1b}[42mstatus.Active\u{1b}[49m | \u{1b}[1mstatus.reason\u{1b}[22m |\n",
+            "+------+--------+--------+---------------+---------------+\n",
+            "| \u{1b}[91mAnn\u{1b}[39m  | Main   |      7 | \u{1b}[42m+\u{1b}[49m             | \u{1b}[1m\u{1b}[22m              |\n",
+            "+------+--------+--------+---------------+---------------+",
+        )
+    );
+}
+
+#[test]
+fn test_column_settings_default() {
+    #[derive(Tabled)]
+    struct Plain {
+        f1: u8,
+        #[tabled(inline)]
+        f2: (u8, u8),
+    }
+
+    assert!(<u8 as Tabled>::column_settings().is_empty());
+    assert!(Plain::column_settings().iter().all(|s| s.is_empty()));
+    assert_eq!(Plain::column_settings().len(), 3);
+}
+
+#[test]
+fn test_generic_struct() {
+    use std::fmt::Display;
+
+    #[derive(Tabled)]
+    struct Row<'a, T, U, F> {
+        id: &'a T,
+        #[tabled(inline)]
+        inner: U,
+        #[tabled(display_with = "display_len")]
+        list: Vec<F>,
+        #[tabled(skip)]
+        #[allow(dead_code)]
+        hidden: F,
+    }
+
+    fn display_len<F>(list: &[F]) -> String {
+        list.len().to_string()
+    }
+
+    fn assert_tabled<T: Tabled>(value: T) -> Vec<String> {
+        value.fields().into_iter().map(|f| f.into_owned()).collect()
+    }
+
+    let row = Row {
+        id: &1,
+        inner: ("a", 2.5),
+        list: vec![Vec::<u8>::new(); 3],
+        hidden: Vec::new(),
+    };
+
+    assert_eq!(assert_tabled(row), ["1", "a", "2.5", "3"]);
+    assert_eq!(
+        Row::<u8, (&str, f32), ()>::headers(),
+        ["id", "&str", "f32", "list"]
+    );
+
+    #[derive(Tabled)]
+    struct Wrapper<T: Display>(T)
+    where
+        T: Clone;
+
+    assert_eq!(Wrapper("text").fields(), ["text"]);
+}
+
+#[test]
+fn test_generic_enum() {
+    #[derive(Tabled)]
+    enum Either<L, R, N> {
+        #[tabled(inline("left."))]
+        Left(L),
+        #[tabled(inline)]
+        Right(#[tabled(inline)] R),
+        Nothing(#[allow(dead_code)] N),
+    }
+
+    let value: Either<i32, (u8, u8), ()> = Either::Left(1);
+
+    assert_eq!(value.fields(), ["1", "", "", ""]);
+
+    let value: Either<i32, (u8, u8), ()> = Either::Right((2, 3));
+    assert_eq!(value.fields(), ["", "2", "3", ""]);
+
+    let value: Either<i32, (u8, u8), ()> = Either::Nothing(());
+    assert_eq!(value.fields(), ["", "", "", "+"]);
+    assert_eq!(
+        Either::<i32, (u8, u8), ()>::headers(),
+        ["left.0", "u8", "u8", "Nothing"]
+    );
+}
+
+#[test]
+fn test_generic_bound() {
+    use std::fmt::Display;
+
+    trait Named {
+        fn name(&self) -> String;
+    }
+
+    impl Named for u8 {
+        fn name(&self) -> String {
+            format!("u8({self})")
+        }
+    }
+
+    #[derive(Tabled)]
+    #[tabled(bound = "T: Named")]
+    struct Row<T> {
+        #[tabled(display_with = "Named::name")]
+        value: T,
+    }
+
+    assert_eq!(Row { value: 1u8 }.fields(), ["u8(1)"]);
+
+    #[derive(Tabled)]
+    struct Pair<T, U> {
+        #[tabled(bound = "T: Display + Copy")]
+        first: T,
+        second: U,
+    }
+
+    assert_eq!(
+        Pair {
+            first: 1,
+            second: "2"
+        }
+        .fields(),
+        ["1", "2"]
+    );
+
+    #[derive(Tabled)]
+    #[tabled(bound = "")]
+    struct Marker<T> {
+        #[tabled(display_with = "marker")]
+        value: std::marker::PhantomData<T>,
+    }
+
+    fn marker<T>(_: &T) -> &'static str {
+        "marker"
+    }
+
+    struct NotDisplay;
+
+    assert_eq!(
+        Marker::<NotDisplay> {
+            value: std::marker::PhantomData
+        }
+        .fields(),
+        ["marker"]
+    );
+}
+
+#[test]
+fn test_generic_values() {
+    use tabled::{TabledValue, TabledValues};
+
+    #[derive(Tabled, TabledValues)]
+    struct Row<T, D: std::fmt::Display> {
+        value: T,
+        #[tabled(datetime)]
+        date: D,
+    }
+
+    let row = Row {
+        value: 1.5,
+        date: "2024-01-01",
+    };
+
+    assert_eq!(
+        row.values(),
+        [
+            TabledValue::Float(1.5),
+            TabledValue::DateTime("2024-01-01".into())
+        ]
+    );
+}
+
+#[test]
+fn test_generic_display_option() {
+    use tabled::FromTable;
+
+    #[derive(Tabled, FromTable, Debug, PartialEq)]
+    struct Row<T> {
+        #[tabled(display_option = "-")]
+        value: Option<T>,
+    }
+
+    let rows = vec![Row { value: Some(1) }, Row { value: None }];
+
+    assert_eq!(rows[0].fields(), ["1"]);
+    assert_eq!(rows[1].fields(), ["-"]);
+    assert_eq!(
+        Row::from_table(&tabled::Table::new(&rows).to_string()),
+        Ok(rows)
+    );
+}
+
+#[test]
+fn test_generic_join() {
+    use tabled::{FromTable, TabledValue, TabledValues};
+
+    #[derive(Tabled, TabledValues, FromTable, Debug, PartialEq)]
+    struct Row<T> {
+        #[tabled(join = ", ")]
+        list: Vec<T>,
+    }
+
+    let rows = vec![Row { list: vec![1, 2] }];
+
+    assert_eq!(rows[0].fields(), ["1, 2"]);
+    assert_eq!(rows[0].values(), [TabledValue::String("1, 2".into())]);
+    assert_eq!(
+        Row::from_table(&tabled::Table::new(&rows).to_string()),
+        Ok(rows)
+    );
+}
+
+#[test]
+fn test_generic_nested_table() {
+    use tabled::{Table, TabledValues};
+
+    #[derive(Tabled, TabledValues)]
+    struct Row<T> {
+        #[tabled(nested_table)]
+        list: Vec<T>,
+    }
+
+    let row = Row {
+        list: vec![(1, "a")],
+    };
+
+    assert_eq!(row.fields(), [Table::new([(1, "a")]).to_string()],);
+    assert_eq!(row.values().len(), 1);
+}
+
+#[test]
+fn test_display_option() {
+    #[derive(Tabled)]
+    struct Row {
+        #[tabled(display_option = "-")]
+        f1: Option<u8>,
+        #[tabled(display_option = "")]
+        f2: Option<String>,
+    }
+
+    let row = Row {
+        f1: None,
+        f2: Some(String::from("Hello")),
+    };
+
+    assert_eq!(row.fields(), ["-", "Hello"]);
+}
+
+#[test]
+fn test_display_option_enum() {
+    #[derive(Tabled)]
+    enum Event {
+        #[tabled(inline)]
+        Start {
+            #[tabled(display_option = "unknown")]
+            at: Option<u32>,
+        },
+    }
+
+    assert_eq!(Event::Start { at: None }.fields(), ["unknown"]);
+    assert_eq!(Event::Start { at: Some(1) }.fields(), ["1"]);
+}
+
+#[test]
+fn test_join() {
+    #[derive(Tabled)]
+    struct Row {
+        #[tabled(join = ", ")]
+        f1: Vec<u8>,
+        #[tabled(join = "|")]
+        f2: [&'static str; 2],
+        #[tabled(join = ",")]
+        f3: Vec<String>,
+    }
+
+    let row = Row {
+        f1: vec![1, 2, 3],
+        f2: ["a", "b"],
+        f3: vec![],
+    };
+
+    assert_eq!(row.fields(), ["1, 2, 3", "a|b", ""]);
+}
+
+#[test]
+fn test_display_option_and_join_values() {
+    use tabled::{TabledValue, TabledValues};
+
+    #[derive(Tabled, TabledValues)]
+    struct Row {
+        #[tabled(display_option = "-")]
+        f1: Option<u8>,
+        #[tabled(join = ", ")]
+        f2: Vec<u8>,
+    }
+
+    let row = Row {
+        f1: None,
+        f2: vec![1, 2],
+    };
+
+    assert_eq!(
+        row.values(),
+        [TabledValue::Null, TabledValue::String("1, 2".into())]
+    );
+}
+
+#[test]
+fn test_nested_table() {
+    use tabled::{settings::Style, Table};
+
+    #[derive(Tabled)]
+    struct Author {
+        name: &'static str,
+        commits: u32,
+    }
+
+    #[derive(Tabled)]
+    struct Project {
+        name: &'static str,
+        #[tabled(nested_table)]
+        authors: Vec<Author>,
+    }
+
+    let projects = [Project {
+        name: "tabled",
+        authors: vec![
+            Author {
+                name: "Maxim",
+                commits: 1024,
+            },
+            Author {
+                name: "Ann",
+                commits: 8,
+            },
+        ],
+    }];
+
+    assert_eq!(
+        projects[0].fields()[1],
+        concat!(
+            "+-------+---------+\n",
+            "| name  | commits |\n",
+            "+-------+---------+\n",
+            "| Maxim | 1024    |\n",
+            "+-------+---------+\n",
+            "| Ann   | 8       |\n",
+            "+-------+---------+",
+        )
+    );
+
+    let table = Table::new(projects).with(Style::psql()).to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            " name   | authors             \n",
+            "--------+---------------------\n",
+            " tabled | +-------+---------+ \n",
+            "        | | name  | commits | \n",
+            "        | +-------+---------+ \n",
+            "        | | Maxim | 1024    | \n",
+            "        | +-------+---------+ \n",
+            "        | | Ann   | 8       | \n",
+            "        | +-------+---------+ ",
+        )
+    );
+}
+
+#[test]
+fn test_presentation() {
+    use tabled::Table;
+
+    #[derive(Tabled)]
+    #[tabled(style = "modern", header = "Users", footer_fn = "Self::totals")]
+    struct User {
+        name: &'static str,
+        age: u8,
+    }
+
+    impl User {
+        fn totals(rows: &[&Self]) -> String {
+            format!("total {}", rows.len())
+        }
+    }
+
+    let users = vec![
+        User {
+            name: "Maxim",
+            age: 27,
+        },
+        User {
+            name: "Ann",
+            age: 9,
+        },
+    ];
+
+    let expected = concat!(
+        "┌───────┬─────┐\n",
+        "│ Users       │\n",
+        "├───────┼─────┤\n",
+        "│ name  │ age │\n",
+        "├───────┼─────┤\n",
+        "│ Maxim │ 27  │\n",
+        "├───────┼─────┤\n",
+        "│ Ann   │ 9   │\n",
+        "├───────┼─────┤\n",
+        "│ total 2     │\n",
+        "└───────┴─────┘",
+    );
+
+    assert!(has_presentation::<&User>());
+    assert_eq!(Table::new(&users).to_string(), expected);
+    assert_eq!(Table::new(users).to_string(), expected);
+}
+
+fn has_presentation<T: Tabled>() -> bool {
+    T::HAS_PRESENTATION
+}
+
+#[test]
+fn test_presentation_style_only() {
+    use tabled::{Table, TabledPresentation};
+
+    #[derive(Tabled)]
+    #[tabled(style = "psql")]
+    enum Status {
+        Active,
+        Blocked,
+    }
+
+    assert!(Status::header().is_none());
+    assert!(has_presentation::<Status>());
+    assert!(!has_presentation::<(u8, &str)>());
+    assert_eq!(
+        Table::new([Status::Active, Status::Blocked]).to_string(),
+        concat!(
+            " Active | Blocked \n",
+            "--------+---------\n",
+            " +      |         \n",
+            "        | +       ",
+        )
+    );
+}
+
+#[test]
+fn test_column_groups() {
+    use tabled::Table;
+
+    #[derive(Tabled)]
+    struct Address {
+        city: &'static str,
+        street: &'static str,
+    }
+
+    #[derive(Tabled)]
+    struct User {
+        name: &'static str,
+        #[tabled(inline, group = "Address")]
+        address: Address,
+        #[tabled(group = "Contact")]
+        phone: &'static str,
+    }
+
+    let users = [User {
+        name: "Maxim",
+        address: Address {
+            city: "Kyiv",
+            street: "Khreshchatyk",
+        },
+        phone: "+380",
+    }];
+
+    assert_eq!(
+        User::column_groups(),
+        [
+            None,
+            Some("Address".into()),
+            Some("Address".into()),
+            Some("Contact".into())
+        ]
+    );
+
+    assert_eq!(
+        Table::new(users).to_string(),
+        concat!(
+            "+-------+------+--------------+---------+\n",
+            "| name  | Address             | Contact |\n",
+            "+       +------+--------------+---------+\n",
+            "|       | city | street       | phone   |\n",
+            "+-------+------+--------------+---------+\n",
+            "| Maxim | Kyiv | Khreshchatyk | +380    |\n",
+            "+-------+------+--------------+---------+",
+        )
+    );
+}
+
+#[test]
+fn test_column_groups_nested() {
+    #[derive(Tabled)]
+    struct Point {
+        #[tabled(group = "Coordinates")]
+        x: u8,
+        #[tabled(group = "Coordinates")]
+        y: u8,
+    }
+
+    #[derive(Tabled)]
+    struct Shape {
+        #[tabled(inline)]
+        start: Point,
+        #[tabled(skip)]
+        #[allow(dead_code)]
+        hidden: u8,
+        name: &'static str,
+    }
+
+    assert_eq!(
+        Shape::column_groups(),
+        [Some("Coordinates".into()), Some("Coordinates".into()), None]
+    );
+    assert!(<(u8, Point)>::column_groups()[0].is_none());
+    assert!(<u8 as Tabled>::column_groups().is_empty());
+}
+
+#[test]
+fn test_from_table() {
+    use tabled::{
+        settings::{themes::Theme, Style},
+        FromTable, Table,
+    };
+
+    #[derive(Tabled, FromTable, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Tabled, FromTable, Debug, PartialEq)]
+    struct Shape {
+        #[tabled(rename = "Name", order = 2)]
+        name: String,
+        #[tabled(inline("start."))]
+        start: Point,
+        #[tabled(skip)]
+        id: u64,
+        closed: bool,
+    }
+
+    let shapes = vec![
+        Shape {
+            name: String::from("line"),
+            start: Point { x: 1, y: -2 },
+            id: 0,
+            closed: false,
+        },
+        Shape {
+            name: String::from("square | box"),
+            start: Point { x: 0, y: 0 },
+            id: 0,
+            closed: true,
+        },
+    ];
+
+    // a pipe can't be told apart from a vertical line of an ascii style
+    let table = Table::new(&shapes).with(Style::modern()).to_string();
+    assert_eq!(Shape::from_table(&table).as_ref(), Ok(&shapes));
+
+    let shapes = &shapes[..1];
+    for theme in [
+        Theme::from_style(Style::ascii()),
+        Theme::from_style(Style::psql()),
+        Theme::from_style(Style::markdown()),
+        Theme::from_style(Style::rounded()),
+        Theme::from_style(Style::ascii_rounded()),
+        Theme::from_style(Style::dots()),
+    ] {
+        let table = Table::new(shapes).with(theme).to_string();
+        assert_eq!(Shape::from_table(&table).as_deref(), Ok(shapes));
+    }
+}
+
+#[test]
+fn test_from_table_tuple_struct() {
+    use tabled::FromTable;
+
+    #[derive(Tabled, FromTable, Debug, PartialEq)]
+    struct Pair(u8, #[tabled(display_option = "none")] Option<char>);
+
+    let table = " 0 | 1    \n---+------\n 1 | a    \n 2 | none ";
+
+    assert_eq!(
+        Pair::from_table(table),
+        Ok(vec![Pair(1, Some('a')), Pair(2, None)])
+    );
+}
+
+#[test]
+fn test_from_table_error() {
+    use tabled::{FromTable, FromTableError};
+
+    #[derive(Tabled, FromTable, Debug, PartialEq)]
+    struct Row {
+        name: String,
+        #[tabled(inline)]
+        value: (u8, u8),
+    }
+
+    let table =
+        "| name | u8 | u8  |\n|------|----|-----|\n| a    | 1  | 2   |\n| b    | 3  | 256 |";
+    assert_eq!(
+        Row::from_table(table),
+        Err(FromTableError::InvalidValue {
+            row: 1,
+            column: 2,
+            text: String::from("256")
+        })
+    );
+
+    assert_eq!(
+        Row::from_table("| id |\n|----|\n| 1  |"),
+        Err(FromTableError::MissingColumn(String::from("name")))
+    );
+
+    assert_eq!(
+        Row::from_table("| name | u8 | u8 |\n| a | 1 |"),
+        Err(FromTableError::InvalidRow(0))
+    );
+}
+
+#[test]
+fn test_from_table_duplicate_headers() {
+    use tabled::{FromTable, FromTableError};
+
+    #[derive(Tabled, FromTable, Debug, PartialEq)]
+    struct Range {
+        #[tabled(rename = "bound")]
+        start: u8,
+        #[tabled(rename = "bound")]
+        end: u8,
+    }
+
+    assert_eq!(
+        Range::from_table("| bound | bound |\n|-------|-------|\n| 1     | 5     |"),
+        Ok(vec![Range { start: 1, end: 5 }])
+    );
+
+    assert_eq!(
+        Range::from_table("| bound | id |\n|-------|----|\n| 1     | 5  |"),
+        Err(FromTableError::MissingColumn(String::from("bound")))
+    );
+}
+
+#[test]
+fn test_enum_tagged() {
+    use tabled::Table;
+
+    #[derive(Tabled)]
+    #[tabled(enum_mode = "tagged")]
+    enum Event {
+        Login {
+            user: &'static str,
+        },
+        #[tabled(rename = "transfer")]
+        Transfer {
+            user: &'static str,
+            #[tabled(align = "right")]
+            amount: u32,
+        },
+        Logout,
+        #[tabled(skip)]
+        #[allow(dead_code)]
+        Hidden(u8),
+    }
+
+    assert_eq!(Event::LENGTH, 3);
+    assert_eq!(Event::headers(), ["kind", "user", "amount"]);
+    assert_eq!(
+        Event::Transfer {
+            user: "ann",
+            amount: 10
+        }
+        .fields(),
+        ["transfer", "ann", "10"]
+    );
+    assert_eq!(Event::Hidden(1).fields(), ["", "", ""]);
+
+    let events = [
+        Event::Login { user: "ann" },
+        Event::Transfer {
+            user: "ann",
+            amount: 1000,
+        },
+        Event::Logout,
+    ];
+
+    assert_eq!(
+        Table::new(events).to_string(),
+        concat!(
+            "+----------+------+--------+\n",
+            "| kind     | user | amount |\n",
+            "+----------+------+--------+\n",
+            "| Login    | ann  |        |\n",
+            "+----------+------+--------+\n",
+            "| transfer | ann  |   1000 |\n",
+            "+----------+------+--------+\n",
+            "| Logout   |      |        |\n",
+            "+----------+------+--------+",
+        )
+    );
+}
+
+#[test]
+fn test_enum_tagged_values() {
+    use tabled::{TabledValue, TabledValues};
+
+    #[derive(Tabled, TabledValues)]
+    #[tabled(enum_mode = "tagged", rename_all = "UPPERCASE")]
+    enum Shape<T> {
+        Circle(T),
+        Rect { width: T, height: T },
+    }
+
+    assert_eq!(Shape::<u8>::headers(), ["kind", "0", "WIDTH", "HEIGHT"]);
+    assert_eq!(
+        Shape::Rect {
+            width: 1.5,
+            height: 2.0
+        }
+        .values(),
+        [
+            TabledValue::String("RECT".into()),
+            TabledValue::Null,
+            TabledValue::Float(1.5),
+            TabledValue::Float(2.0),
+        ]
+    );
+    assert_eq!(Shape::Circle(1).fields(), ["CIRCLE", "1", "", ""]);
+}
+
 mod __ {
     #[test]
     fn dont_import_the_trait() {
         #[derive(tabled::Tabled)]
         struct __;
     }
+
+    #[test]
+    fn dont_import_the_values_trait() {
+        #[derive(tabled::Tabled, tabled::TabledValues)]
+        struct __ {
+            f1: u8,
+            #[tabled(inline)]
+            f2: (bool, String),
+        }
+    }
+}
+
+#[test]
+fn test_display_if() {
+    #[derive(Tabled)]
+    struct Account {
+        name: &'static str,
+        #[tabled(display_if = "is_positive")]
+        balance: i64,
+    }
+
+    fn is_positive(balance: &i64) -> bool {
+        *balance > 0
+    }
+
+    let account = Account {
+        name: "Ann",
+        balance: 100,
+    };
+    assert_eq!(account.fields(), ["Ann", "100"]);
+
+    let account = Account {
+        name: "Tom",
+        balance: -5,
+    };
+    assert_eq!(account.fields(), ["Tom", ""]);
+}
+
+#[test]
+fn test_skip_if_all() {
+    use tabled::Table;
+
+    #[derive(Tabled)]
+    struct Task {
+        name: &'static str,
+        #[tabled(skip_if_all = "Option::is_none", display_option = "")]
+        owner: Option<&'static str>,
+        #[tabled(skip_if_all = "str::is_empty")]
+        note: &'static str,
+    }
+
+    let tasks = [
+        Task {
+            name: "build",
+            owner: None,
+            note: "fast",
+        },
+        Task {
+            name: "test",
+            owner: None,
+            note: "",
+        },
+    ];
+
+    assert_eq!(
+        Table::new(&tasks).to_string(),
+        concat!(
+            "+-------+------+\n",
+            "| name  | note |\n",
+            "+-------+------+\n",
+            "| build | fast |\n",
+            "+-------+------+\n",
+            "| test  |      |\n",
+            "+-------+------+",
+        ),
+    );
+
+    let tasks = [Task {
+        name: "build",
+        owner: Some("Ann"),
+        note: "",
+    }];
+
+    assert_eq!(
+        Table::new(&tasks).to_string(),
+        concat!(
+            "+-------+-------+\n",
+            "| name  | owner |\n",
+            "+-------+-------+\n",
+            "| build | Ann   |\n",
+            "+-------+-------+",
+        ),
+    );
+}
+
+#[test]
+fn test_header_key() {
+    use std::collections::HashMap;
+    use tabled::Table;
+
+    #[derive(Tabled)]
+    struct Address {
+        #[tabled(header_key = "address.city")]
+        city: &'static str,
+    }
+
+    #[derive(Tabled)]
+    struct User {
+        #[tabled(header_key = "user.name")]
+        name: &'static str,
+        #[tabled(header_key = "user.age")]
+        age: u8,
+        #[tabled(inline)]
+        address: Address,
+    }
+
+    assert_eq!(
+        User::header_keys(),
+        [
+            Some("user.name".into()),
+            Some("user.age".into()),
+            Some("address.city".into())
+        ]
+    );
+
+    let users = [User {
+        name: "Anna",
+        age: 31,
+        address: Address { city: "Lviv" },
+    }];
+
+    let mut german = HashMap::new();
+    german.insert("user.name", "Name");
+    german.insert("address.city", "Stadt");
+
+    assert_eq!(
+        Table::new_with_headers(&users, &german).to_string(),
+        concat!(
+            "+------+-----+-------+\n",
+            "| Name | age | Stadt |\n",
+            "+------+-----+-------+\n",
+            "| Anna | 31  | Lviv  |\n",
+            "+------+-----+-------+",
+        ),
+    );
+
+    let upper = |key: &str| Some(key.to_uppercase());
+    assert_eq!(
+        Table::new_with_headers(&users, &upper).to_string(),
+        concat!(
+            "+-----------+----------+--------------+\n",
+            "| USER.NAME | USER.AGE | ADDRESS.CITY |\n",
+            "+-----------+----------+--------------+\n",
+            "| Anna      | 31       | Lviv         |\n",
+            "+-----------+----------+--------------+",
+        ),
+    );
 }
@@ -0,0 +1,257 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        aggregate::Aggregate,
+        location::ByColumnName,
+        object::{Cell, Columns},
+        Alignment, Modify, Panel, Style,
+    },
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+fn sales() -> Table {
+    let mut builder = Builder::default();
+    builder.push_record(["region", "product", "units", "price"]);
+    builder.push_record(["North", "apple", "10", "1.5"]);
+    builder.push_record(["South", "apple", "4", "1.25"]);
+    builder.push_record(["North", "pear", "7", "2"]);
+    builder.push_record(["North", "apple", "3", "1.75"]);
+    builder.push_record(["South", "pear", "n/a", "2.5"]);
+    builder.build()
+}
+
+test_table!(
+    aggregate_empty,
+    Matrix::empty().with(Aggregate::group_by(Columns::first()).sum(1)),
+    ""
+);
+
+test_table!(
+    aggregate_total,
+    sales().with(Style::psql()).with(Aggregate::total().sum(2).mean(3)),
+    " region | product | units | price "
+    "--------+---------+-------+-------"
+    " North  | apple   | 10    | 1.5   "
+    " South  | apple   | 4     | 1.25  "
+    " North  | pear    | 7     | 2     "
+    " North  | apple   | 3     | 1.75  "
+    " South  | pear    | n/a   | 2.5   "
+    " Total  |         | 24    | 1.80  "
+);
+
+test_table!(
+    aggregate_group_by,
+    sales().with(Style::psql()).with(Aggregate::group_by(Columns::first()).sum(2).count(2)),
+    " region | product  | units | price "
+    "--------+----------+-------+-------"
+    " North  | apple    | 10    | 1.5   "
+    " North  | pear     | 7     | 2     "
+    " North  | apple    | 3     | 1.75  "
+    " North  | Subtotal | 3     |       "
+    " South  | apple    | 4     | 1.25  "
+    " South  | pear     | n/a   | 2.5   "
+    " South  | Subtotal | 2     |       "
+    " Total  |          | 5     |       "
+);
+
+test_table!(
+    aggregate_group_by_multiple_columns,
+    sales()
+        .with(Style::psql())
+        .with(Aggregate::group_by(Columns::new(0..2)).sum(2).max(3).subtotal_label(Some("-")).total_label(None::<&str>)),
+    " region | product | units | price "
+    "--------+---------+-------+-------"
+    " North  | apple   | 10    | 1.5   "
+    " North  | apple   | 3     | 1.75  "
+    " North  | apple   | 13    | 1.75  "
+    " South  | apple   | 4     | 1.25  "
+    " South  | apple   | 4     | 1.25  "
+    " North  | pear    | 7     | 2     "
+    " North  | pear    | 7     | 2     "
+    " South  | pear    | n/a   | 2.5   "
+    " South  | pear    |       | 2.5   "
+);
+
+test_table!(
+    aggregate_min_max_mean_precision,
+    sales()
+        .with(Style::psql())
+        .with(Aggregate::group_by(Columns::single(1)).min(2).max(3).mean(3).precision(2)),
+    " region   | product | units | price "
+    "----------+---------+-------+-------"
+    " North    | apple   | 10    | 1.5   "
+    " South    | apple   | 4     | 1.25  "
+    " North    | apple   | 3     | 1.75  "
+    " Subtotal | apple   | 3.00  | 1.50  "
+    " North    | pear    | 7     | 2     "
+    " South    | pear    | n/a   | 2.5   "
+    " Subtotal | pear    | 7.00  | 2.25  "
+    " Total    |         | 3.00  | 1.80  "
+);
+
+test_table!(
+    aggregate_keeps_panels,
+    sales()
+        .with(Panel::header("Sales"))
+        .with(Panel::footer("2024"))
+        .with(Aggregate::group_by(Columns::first()).sum(2))
+        .with(Style::psql()),
+    " Sales                             "
+    "--------+----------+-------+-------"
+    " region | product  | units | price "
+    " North  | apple    | 10    | 1.5   "
+    " North  | pear     | 7     | 2     "
+    " North  | apple    | 3     | 1.75  "
+    " North  | Subtotal | 20    |       "
+    " South  | apple    | 4     | 1.25  "
+    " South  | pear     | n/a   | 2.5   "
+    " South  | Subtotal | 4     |       "
+    " Total  |          | 24    |       "
+    " 2024                              "
+);
+
+test_table!(
+    aggregate_summary_rows_modify,
+    {
+        let aggregate = Aggregate::group_by(Columns::first()).sum(2);
+        let summary = aggregate.summary_rows();
+
+        let mut table = sales();
+        table.with(aggregate);
+        table.with(Modify::new(summary.subtotals()).with(Alignment::right()));
+        table.with(Modify::new(summary.total()).with(Alignment::center()));
+        table.with(Style::psql());
+        table
+    },
+    " region | product  | units | price "
+    "--------+----------+-------+-------"
+    " North  | apple    | 10    | 1.5   "
+    " North  | pear     | 7     | 2     "
+    " North  | apple    | 3     | 1.75  "
+    "  North | Subtotal |    20 |       "
+    " South  | apple    | 4     | 1.25  "
+    " South  | pear     | n/a   | 2.5   "
+    "  South | Subtotal |     4 |       "
+    " Total  |          |  24   |       "
+);
+
+test_table!(
+    aggregate_moves_cell_settings,
+    sales()
+        .with(Modify::new(Cell::new(2, 2)).with(Alignment::right()))
+        .with(Aggregate::group_by(Columns::first()).sum(2))
+        .with(Style::psql()),
+    " region | product  | units | price "
+    "--------+----------+-------+-------"
+    " North  | apple    | 10    | 1.5   "
+    " North  | pear     | 7     | 2     "
+    " North  | apple    | 3     | 1.75  "
+    " North  | Subtotal | 20    |       "
+    " South  | apple    |     4 | 1.25  "
+    " South  | pear     | n/a   | 2.5   "
+    " South  | Subtotal | 4     |       "
+    " Total  |          | 24    |       "
+);
+
+test_table!(
+    aggregate_summary_rows_are_not_matched_by_content,
+    {
+        let mut builder = Builder::default();
+        builder.push_record(["account", "amount"]);
+        builder.push_record(["Total", "1"]);
+        builder.push_record(["Sum", "2"]);
+
+        let aggregate = Aggregate::total().sum(1).total_label(Some("Sum"));
+        let summary = aggregate.summary_rows();
+
+        let mut table = builder.build();
+        table.with(aggregate);
+        table.with(Modify::new(summary).with(Alignment::right()));
+        table.with(Style::psql());
+        table
+    },
+    " account | amount "
+    "---------+--------"
+    " Total   | 1      "
+    " Sum     | 2      "
+    "     Sum |      3 "
+);
+
+test_table!(
+    aggregate_summary_rows_before_apply,
+    {
+        let aggregate = Aggregate::total().sum(2);
+        let summary = aggregate.summary_rows();
+
+        let mut table = sales();
+        table.with(Modify::new(summary).with(Alignment::right()));
+        table.with(aggregate);
+        table.with(Style::psql());
+        table
+    },
+    " region | product | units | price "
+    "--------+---------+-------+-------"
+    " North  | apple   | 10    | 1.5   "
+    " South  | apple   | 4     | 1.25  "
+    " North  | pear    | 7     | 2     "
+    " North  | apple   | 3     | 1.75  "
+    " South  | pear    | n/a   | 2.5   "
+    " Total  |         | 24    |       "
+);
+
+test_table!(
+    aggregate_group_by_column_name,
+    sales()
+        .with(Aggregate::group_by(ByColumnName::new("product")).count(0))
+        .with(Style::psql()),
+    " region | product | units    | price "
+    "--------+---------+----------+-------"
+    " North  | apple   | 10       | 1.5   "
+    " South  | apple   | 4        | 1.25  "
+    " North  | apple   | 3        | 1.75  "
+    " 3      | apple   | Subtotal |       "
+    " North  | pear    | 7        | 2     "
+    " South  | pear    | n/a      | 2.5   "
+    " 2      | pear    | Subtotal |       "
+    " 5      | Total   |          |       "
+);
+
+test_table!(
+    aggregate_default_precision,
+    {
+        let mut builder = Builder::default();
+        builder.push_record(["name", "count", "weight"]);
+        builder.push_record(["a", "1", "0.1"]);
+        builder.push_record(["b", "2", "0.2"]);
+        builder.push_record(["c", "7", "0.25"]);
+        builder.build()
+    }
+    .with(Aggregate::total().mean(1).sum(2))
+    .with(Style::psql()),
+    " name  | count | weight "
+    "-------+-------+--------"
+    " a     | 1     | 0.1    "
+    " b     | 2     | 0.2    "
+    " c     | 7     | 0.25   "
+    " Total | 3.33  | 0.55   "
+);
+
+test_table!(
+    aggregate_precision_overrides_default,
+    sales()
+        .with(Aggregate::total().mean(2).sum(3).precision(1))
+        .with(Style::psql()),
+    " region | product | units | price "
+    "--------+---------+-------+-------"
+    " North  | apple   | 10    | 1.5   "
+    " South  | apple   | 4     | 1.25  "
+    " North  | pear    | 7     | 2     "
+    " North  | apple   | 3     | 1.75  "
+    " South  | pear    | n/a   | 2.5   "
+    " Total  |         | 6.0   | 9.0   "
+);
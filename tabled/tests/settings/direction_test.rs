@@ -0,0 +1,164 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::{
+        filter::Filter,
+        object::{Cell, Columns},
+        sort::Sort,
+        Alignment, Direction, Modify, Reverse, Style, Width,
+    },
+    Table,
+};
+
+use testing_table::test_table;
+
+test_table!(
+    direction_not_set,
+    Table::new([("שלום", "abc"), ("עולם", "def")]),
+    "+------+------+"
+    "| &str | &str |"
+    "+------+------+"
+    "| שלום | abc  |"
+    "+------+------+"
+    "| עולם | def  |"
+    "+------+------+"
+);
+
+test_table!(
+    direction_rtl,
+    Table::new([("שלום עולם", "abc"), ("עולם", "def")]).with(Direction::Rtl),
+    "+-----------+------+"
+    "|      str& | str& |"
+    "+-----------+------+"
+    "| םלוע םולש |  abc |"
+    "+-----------+------+"
+    "|      םלוע |  def |"
+    "+-----------+------+"
+);
+
+test_table!(
+    direction_rtl_column,
+    Table::new([("שלום עולם", "abc"), ("עולם", "def")])
+        .with(Modify::new(Columns::first()).with(Direction::Rtl)),
+    "+-----------+------+"
+    "|      str& | &str |"
+    "+-----------+------+"
+    "| םלוע םולש | abc  |"
+    "+-----------+------+"
+    "|      םלוע | def  |"
+    "+-----------+------+"
+);
+
+test_table!(
+    direction_ltr_with_rtl_text,
+    Table::new([("abc שלום עולם def", 1)]).with(Direction::Ltr),
+    "+-------------------+-----+"
+    "| &str              | i32 |"
+    "+-------------------+-----+"
+    "| abc םלוע םולש def | 1   |"
+    "+-------------------+-----+"
+);
+
+test_table!(
+    direction_auto,
+    Table::new([("שלום עולם", "abc"), ("hello שלום", "עולם 2024")]).with(Direction::Auto),
+    "+------------+-----------+"
+    "| &str       | &str      |"
+    "+------------+-----------+"
+    "|  םלוע םולש | abc       |"
+    "+------------+-----------+"
+    "| hello םולש | 2024 םלוע |"
+    "+------------+-----------+"
+);
+
+test_table!(
+    direction_rtl_alignment,
+    Table::new([("שלום עולם", "abc"), ("עולם", "def")])
+        .with(Direction::Rtl)
+        .with(Modify::new(Columns::first()).with(Alignment::right()))
+        .with(Modify::new(Columns::last()).with(Alignment::center())),
+    "+-----------+------+"
+    "| str&      | str& |"
+    "+-----------+------+"
+    "| םלוע םולש | abc  |"
+    "+-----------+------+"
+    "| םלוע      | def  |"
+    "+-----------+------+"
+);
+
+test_table!(
+    direction_rtl_mirrored,
+    Table::new([("שלום", 1, "abc"), ("עולם", 22, "def")])
+        .with(Style::modern())
+        .with(Direction::Rtl)
+        .with(Reverse::columns(0, 0)),
+    "┌──────┬─────┬──────┐"
+    "│ str& │ i32 │ str& │"
+    "├──────┼─────┼──────┤"
+    "│  abc │   1 │ םולש │"
+    "├──────┼─────┼──────┤"
+    "│  def │  22 │ םלוע │"
+    "└──────┴─────┴──────┘"
+);
+
+test_table!(
+    direction_rtl_wrap,
+    Table::new([("שלום עולם ומה שלומך היום", 1)])
+        .with(Direction::Rtl)
+        .with(Modify::new(Columns::first()).with(Width::wrap(10).keep_words(true))),
+    "+------------+-----+"
+    "|       str& | i32 |"
+    "+------------+-----+"
+    "|  םלוע םולש |   1 |"
+    "|  ךמולש המו |     |"
+    "|       םויה |     |"
+    "+------------+-----+"
+);
+
+test_table!(
+    direction_rtl_numbers,
+    Table::new([("מחיר: 1,000 (USD)", 1)]).with(Direction::Rtl),
+    "+-------------------+-----+"
+    "|              str& | i32 |"
+    "+-------------------+-----+"
+    "| (USD) 1,000 :ריחמ |   1 |"
+    "+-------------------+-----+"
+);
+
+test_table!(
+    direction_is_moved_by_sort,
+    Table::new([("b", "שלום עולם"), ("a", "abc")])
+        .with(Modify::new(Cell::new(1, 1)).with(Direction::Rtl))
+        .with(Sort::column(Columns::first())),
+    "+------+-----------+"
+    "| &str | &str      |"
+    "+------+-----------+"
+    "| a    | abc       |"
+    "+------+-----------+"
+    "| b    | םלוע םולש |"
+    "+------+-----------+"
+);
+
+test_table!(
+    direction_is_moved_by_filter,
+    Table::new([("a", "abc"), ("b", "שלום עולם")])
+        .with(Modify::new(Cell::new(2, 1)).with(Direction::Rtl))
+        .with(Filter::rows(|row, _| row[0].as_ref() != "a")),
+    "+------+-----------+"
+    "| &str | &str      |"
+    "+------+-----------+"
+    "| b    | םלוע םולש |"
+    "+------+-----------+"
+);
+
+test_table!(
+    direction_is_moved_by_filter_columns,
+    Table::new([("a", "abc")])
+        .with(Modify::new(Cell::new(1, 1)).with(Direction::Rtl))
+        .with(Filter::columns(|col, _| col != 0)),
+    "+------+"
+    "| &str |"
+    "+------+"
+    "|  abc |"
+    "+------+"
+);
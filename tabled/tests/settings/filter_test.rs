@@ -0,0 +1,186 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        filter::Filter,
+        location::Locator,
+        object::{Cell, Columns, Object, Rows},
+        Alignment, Modify, Panel, Span, Style,
+    },
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+fn people() -> Table {
+    let mut builder = Builder::default();
+    builder.push_record(["name", "age", "city"]);
+    builder.push_record(["Tom", "27", "Minsk"]);
+    builder.push_record(["Ann", "9", "Grodno"]);
+    builder.push_record(["Bob", "41", "Brest"]);
+    builder.push_record(["Eve", "9", "Gomel"]);
+    builder.build()
+}
+
+fn is_adult(row: &[tabled::grid::records::vec_records::Text<String>]) -> bool {
+    matches!(row[1].as_ref().parse::<usize>(), Ok(age) if age >= 18)
+}
+
+test_table!(
+    filter_empty,
+    Matrix::empty().with(Filter::rows(|_, _| false)),
+    ""
+);
+
+test_table!(
+    filter_rows,
+    people().with(Style::psql()).with(Filter::rows(|row, _| is_adult(row))),
+    " name | age | city  "
+    "------+-----+-------"
+    " Tom  | 27  | Minsk "
+    " Bob  | 41  | Brest "
+);
+
+test_table!(
+    filter_rows_by_header,
+    people().with(Style::psql()).with(Filter::rows(|row, header| {
+        let city = header.iter().position(|text| text.as_ref() == "city").unwrap();
+        row[city].as_ref().starts_with('G')
+    })),
+    " name | age | city   "
+    "------+-----+--------"
+    " Ann  | 9   | Grodno "
+    " Eve  | 9   | Gomel  "
+);
+
+test_table!(
+    filter_rows_none,
+    people().with(Style::psql()).with(Filter::rows(|_, _| false)),
+    " name | age | city "
+    "------+-----+------"
+);
+
+test_table!(
+    filter_rows_without_header,
+    people().with(Style::psql()).with(Filter::rows(|row, header| header.is_empty() && is_adult(row)).header(0)),
+    " Tom | 27 | Minsk "
+    "-----+----+-------"
+    " Bob | 41 | Brest "
+);
+
+test_table!(
+    filter_cells,
+    people().with(Style::psql()).with(Filter::cells(Locator::by(|text| text.contains('o')))),
+    " name | age | city   "
+    "------+-----+--------"
+    " Tom  | 27  | Minsk  "
+    " Ann  | 9   | Grodno "
+    " Bob  | 41  | Brest  "
+    " Eve  | 9   | Gomel  "
+);
+
+test_table!(
+    filter_cells_intersect,
+    people()
+        .with(Style::psql())
+        .with(Filter::cells(Columns::first().intersect(Locator::by(|text| text.contains('o'))))),
+    " name | age | city  "
+    "------+-----+-------"
+    " Tom  | 27  | Minsk "
+    " Bob  | 41  | Brest "
+);
+
+test_table!(
+    filter_cells_by_rows,
+    people().with(Style::psql()).with(Filter::cells(Rows::new(3..))),
+    " name | age | city  "
+    "------+-----+-------"
+    " Bob  | 41  | Brest "
+    " Eve  | 9   | Gomel "
+);
+
+test_table!(
+    filter_keeps_panels,
+    people()
+        .with(Panel::header("People"))
+        .with(Panel::footer("..."))
+        .with(Filter::rows(|row, _| is_adult(row)))
+        .with(Style::psql()),
+    " People             "
+    "------+-----+-------"
+    " name | age | city  "
+    " Tom  | 27  | Minsk "
+    " Bob  | 41  | Brest "
+    " ...                "
+);
+
+test_table!(
+    filter_keeps_cell_settings,
+    people()
+        .with(Modify::new(Columns::new(..)).with(Alignment::center()))
+        .with(Modify::new(Cell::new(3, 2)).with(Alignment::right()))
+        .with(Modify::new(Cell::new(4, 0)).with(Span::column(2)))
+        .with(Filter::rows(|row, _| row[0].as_ref() != "Ann"))
+        .with(Style::psql()),
+    " name | age | city  "
+    "------+-----+-------"
+    " Tom  | 27  | Minsk "
+    " Bob  | 41  | Brest "
+    "    Eve     | Gomel "
+);
+
+test_table!(
+    filter_shrinks_row_spans,
+    people()
+        .with(Modify::new(Cell::new(1, 2)).with(Span::row(3)))
+        .with(Filter::rows(|row, _| row[0].as_ref() != "Ann"))
+        .with(Style::psql()),
+    " name | age | city  "
+    "------+-----+-------"
+    " Tom  | 27  | Minsk "
+    " Bob  | 41  |       "
+    " Eve  | 9   | Gomel "
+);
+
+test_table!(
+    filter_columns,
+    people()
+        .with(Style::psql())
+        .with(Filter::columns(|_, cells| cells.iter().all(|cell| cell.as_ref().len() < 5))),
+    " name | age "
+    "------+-----"
+    " Tom  | 27  "
+    " Ann  | 9   "
+    " Bob  | 41  "
+    " Eve  | 9   "
+);
+
+test_table!(
+    filter_columns_by_index,
+    people().with(Style::psql()).with(Filter::columns(|col, _| col != 1)),
+    " name | city   "
+    "------+--------"
+    " Tom  | Minsk  "
+    " Ann  | Grodno "
+    " Bob  | Brest  "
+    " Eve  | Gomel  "
+);
+
+test_table!(
+    filter_columns_keeps_cell_settings,
+    people()
+        .with(Modify::new(Columns::single(2)).with(Alignment::right()))
+        .with(Panel::header("People"))
+        .with(Modify::new(Cell::new(2, 0)).with(Span::column(2)))
+        .with(Filter::columns(|col, _| col != 0))
+        .with(Style::psql()),
+    " People       "
+    "-----+--------"
+    " age |   city "
+    " Tom |  Minsk "
+    " 9   | Grodno "
+    " 41  |  Brest "
+    " 9   |  Gomel "
+);
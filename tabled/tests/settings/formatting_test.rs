@@ -1,6 +1,14 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{formatting::Justification, object::Columns, Color, Modify};
+use tabled::{
+    builder::Builder,
+    settings::{
+        formatting::{Justification, NumberFormat},
+        object::{Columns, Rows},
+        Alignment, Color, Modify, Style, Width,
+    },
+    Table,
+};
 
 use crate::matrix::Matrix;
 use testing_table::test_table;
@@ -66,3 +74,140 @@ test_table!(
     "| 2 | \u{1b}[44m##\u{1b}[49m2-0\u{1b}[44m###\u{1b}[49m | \u{1b}[41m@@\u{1b}[49m2-1\u{1b}[41m@@@\u{1b}[49m | \u{1b}[47m$$\u{1b}[49m2-2\u{1b}[47m$$$\u{1b}[49m |"
     "+---+----------+----------+----------+"
 );
+
+fn prices() -> Table {
+    let mut builder = Builder::default();
+    builder.push_record(["item", "price"]);
+    builder.push_record(["tea", "3.5"]);
+    builder.push_record(["cake", "12.75"]);
+    builder.push_record(["table", "1250"]);
+    builder.push_record(["sugar", "0.125"]);
+    builder.build()
+}
+
+test_table!(
+    decimal_alignment,
+    prices()
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(Alignment::decimal('.'))),
+    " item  |    price "
+    "-------+----------"
+    " tea   |    3.5   "
+    " cake  |   12.75  "
+    " table | 1250     "
+    " sugar |    0.125 "
+);
+
+test_table!(
+    decimal_alignment_table,
+    prices().with(Style::psql()).with(Alignment::decimal('.')),
+    "  item |    price "
+    "-------+----------"
+    "   tea |    3.5   "
+    "  cake |   12.75  "
+    " table | 1250     "
+    " sugar |    0.125 "
+);
+
+test_table!(
+    decimal_alignment_comma,
+    Table::new([["1,5"], ["-10,25"], ["abc"]])
+        .with(Style::psql())
+        .with(Alignment::decimal(',')),
+    "   0    "
+    "--------"
+    "   1,5  "
+    " -10,25 "
+    "    abc "
+);
+
+test_table!(
+    decimal_alignment_multiline,
+    Table::new([["1.5\n100"], ["22.25"]])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(NumberFormat::new().thousands(',')))
+        .with(Alignment::decimal('.')),
+    "   0    "
+    "--------"
+    "   1.5  "
+    " 100    "
+    "  22.25 "
+);
+
+test_table!(
+    decimal_alignment_is_idempotent,
+    prices()
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(Alignment::decimal('.')))
+        .with(Modify::new(Columns::single(1)).with(Alignment::decimal('.'))),
+    " item  |    price "
+    "-------+----------"
+    " tea   |    3.5   "
+    " cake  |   12.75  "
+    " table | 1250     "
+    " sugar |    0.125 "
+);
+
+test_table!(
+    number_format,
+    prices()
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(NumberFormat::new().precision(2).thousands(' ')))
+        .with(Modify::new(Columns::single(1)).with(Alignment::decimal('.'))),
+    " item  |    price "
+    "-------+----------"
+    " tea   |     3.50 "
+    " cake  |    12.75 "
+    " table | 1 250.00 "
+    " sugar |     0.13 "
+);
+
+test_table!(
+    number_format_big_number,
+    Table::new([["12345678901234567891"], ["+0.5"]])
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..)).with(NumberFormat::new().precision(1).thousands(','))),
+    " 0                            "
+    "------------------------------"
+    " 12,345,678,901,234,567,891.0 "
+    " +0.5                         "
+);
+
+test_table!(
+    decimal_alignment_after_width,
+    prices()
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(Alignment::decimal('.')))
+        .with(Width::increase(20)),
+    " item   |     price "
+    "--------+-----------"
+    " tea    |     3.5   "
+    " cake   |    12.75  "
+    " table  |  1250     "
+    " sugar  |     0.125 "
+);
+
+#[test]
+fn decimal_alignment_keeps_content() {
+    let mut table = prices();
+    table.with(Modify::new(Columns::single(1)).with(Alignment::decimal('.')));
+
+    assert_eq!(
+        table.to_csv(),
+        "item,price\ntea,3.5\ncake,12.75\ntable,1250\nsugar,0.125\n"
+    );
+    assert_eq!(table.get_records()[1][1].as_ref(), "3.5");
+    assert_eq!(table.total_width(), 20);
+}
+
+#[cfg(feature = "ansi")]
+test_table!(
+    decimal_alignment_colored,
+    Table::new([["\u{1b}[31m1.5\u{1b}[39m"], ["22.25"]])
+        .with(Style::psql())
+        .with(Alignment::decimal('.')),
+    "  0    "
+    "-------"
+    "  \u{1b}[31m1.5\u{1b}[39m  "
+    " 22.25 "
+);
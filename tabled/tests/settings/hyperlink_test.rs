@@ -0,0 +1,89 @@
+#![cfg(feature = "ansi")]
+
+use tabled::{
+    settings::{object::Columns, Color, Hyperlink, Modify, Width},
+    Table,
+};
+
+use testing_table::test_table;
+
+fn crate_url(name: &str) -> String {
+    format!("https://crates.io/crates/{}", name)
+}
+
+test_table!(
+    hyperlink_column,
+    Table::new([("tabled", 1), ("papergrid", 2)])
+        .with(Modify::new(Columns::first()).with(Hyperlink::new(crate_url))),
+    "+-----------+-----+"
+    "| \u{1b}]8;;https://crates.io/crates/&str\u{1b}\\&str\u{1b}]8;;\u{1b}\\      | i32 |"
+    "+-----------+-----+"
+    "| \u{1b}]8;;https://crates.io/crates/tabled\u{1b}\\tabled\u{1b}]8;;\u{1b}\\    | 1   |"
+    "+-----------+-----+"
+    "| \u{1b}]8;;https://crates.io/crates/papergrid\u{1b}\\papergrid\u{1b}]8;;\u{1b}\\ | 2   |"
+    "+-----------+-----+"
+);
+
+test_table!(
+    hyperlink_multiline,
+    Table::new(["tabled\n\npapergrid"]).with(Hyperlink::new(crate_url)),
+    "+-----------+"
+    "| \u{1b}]8;;https://crates.io/crates/&str\u{1b}\\&str\u{1b}]8;;\u{1b}\\      |"
+    "+-----------+"
+    "| \u{1b}]8;;https://crates.io/crates/tabled\u{1b}\\tabled\u{1b}]8;;\u{1b}\\    |"
+    "|           |"
+    "| \u{1b}]8;;https://crates.io/crates/papergrid\u{1b}\\papergrid\u{1b}]8;;\u{1b}\\ |"
+    "+-----------+"
+);
+
+test_table!(
+    hyperlink_wrap,
+    Table::new([("tabled", "papergrid")])
+        .with(Hyperlink::new(crate_url))
+        .with(Modify::new(Columns::first()).with(Width::wrap(4))),
+    "+------+-----------+"
+    "| \u{1b}]8;;https://crates.io/crates/&str\u{1b}\\&str\u{1b}]8;;\u{1b}\\ | \u{1b}]8;;https://crates.io/crates/&str\u{1b}\\&str\u{1b}]8;;\u{1b}\\      |"
+    "+------+-----------+"
+    "| \u{1b}]8;;https://crates.io/crates/tabled\u{1b}\\tabl\u{1b}]8;;\u{1b}\\ | \u{1b}]8;;https://crates.io/crates/papergrid\u{1b}\\papergrid\u{1b}]8;;\u{1b}\\ |"
+    "| \u{1b}]8;;https://crates.io/crates/tabled\u{1b}\\ed\u{1b}]8;;\u{1b}\\   |           |"
+    "+------+-----------+"
+);
+
+test_table!(
+    hyperlink_wrap_keep_words,
+    Table::new([("see tabled docs", 1)])
+        .with(Modify::new(Columns::first()).with(Hyperlink::new(|_| String::from("docs.rs"))))
+        .with(Modify::new(Columns::first()).with(Width::wrap(8).keep_words(true))),
+    "+----------+-----+"
+    "| \u{1b}]8;;docs.rs\u{1b}\\&str\u{1b}]8;;\u{1b}\\     | i32 |"
+    "+----------+-----+"
+    "| \u{1b}]8;;docs.rs\u{1b}\\see \u{1b}]8;;\u{1b}\\     | 1   |"
+    "| \u{1b}]8;;docs.rs\u{1b}\\tabled \u{1b}]8;;\u{1b}\\  |     |"
+    "| \u{1b}]8;;docs.rs\u{1b}\\docs\u{1b}]8;;\u{1b}\\     |     |"
+    "+----------+-----+"
+);
+
+test_table!(
+    hyperlink_truncate,
+    Table::new([("papergrid", 1)])
+        .with(Modify::new(Columns::first()).with(Hyperlink::new(crate_url)))
+        .with(Modify::new(Columns::first()).with(Width::truncate(5).suffix("..."))),
+    "+-------+-----+"
+    "| \u{1b}]8;;https://crates.io/crates/&str\u{1b}\\&str\u{1b}]8;;\u{1b}\\  | i32 |"
+    "+-------+-----+"
+    "| \u{1b}]8;;https://crates.io/crates/papergrid\u{1b}\\pa\u{1b}]8;;\u{1b}\\... | 1   |"
+    "+-------+-----+"
+);
+
+test_table!(
+    hyperlink_color_truncate,
+    Table::new([("papergrid", 1)])
+        .with(Modify::new(Columns::first()).with(Hyperlink::new(crate_url)))
+        .with(Modify::new(Columns::first()).with(Color::FG_RED))
+        .with(Width::truncate(12)),
+    "+-------+--+"
+    "| \u{1b}[31m\u{1b}]8;;https://crates.io/crates/&str\u{1b}\\&str\u{1b}]8;;\u{1b}\\\u{1b}[39m  |  |"
+    "+-------+--+"
+    "| \u{1b}[31m\u{1b}]8;;https://crates.io/crates/papergrid\u{1b}\\paper\u{1b}]8;;\u{1b}\\\u{1b}[39m |  |"
+    "+-------+--+"
+);
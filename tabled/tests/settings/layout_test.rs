@@ -1,6 +1,10 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{themes::Layout, Alignment, Style};
+use tabled::{
+    builder::Builder,
+    settings::{themes::Layout, Alignment, Style},
+    Table,
+};
 
 use crate::matrix::Matrix;
 use testing_table::test_table;
@@ -120,3 +124,138 @@ test_table!(
     Matrix::empty().with(Layout::new(Alignment::left(), false)),
     ""
 );
+
+fn packages() -> Table {
+    let mut builder = Builder::default();
+    builder.push_record(["id", "path", "description"]);
+    builder.push_record(["1", "/usr/local/bin/tabled", "Renders tables in a terminal"]);
+    builder.push_record([
+        "2",
+        "/etc/tabled/config.toml",
+        "Configuration of a renderer",
+    ]);
+    builder.build()
+}
+
+fn crates() -> Builder {
+    let mut builder = Builder::default();
+    builder.push_record(["name", "version", "license", "repository", "description"]);
+    builder.push_record([
+        "tabled",
+        "0.15.0",
+        "MIT",
+        "github.com/zhiburt/tabled",
+        "Pretty print tables",
+    ]);
+    builder
+}
+
+test_table!(
+    fit_does_nothing_when_table_fits,
+    packages().with(Style::psql()).with(Layout::fit(80)),
+    " id | path                    | description                  "
+    "----+-------------------------+------------------------------"
+    " 1  | /usr/local/bin/tabled   | Renders tables in a terminal "
+    " 2  | /etc/tabled/config.toml | Configuration of a renderer  "
+);
+
+test_table!(
+    fit_wraps_prose_and_truncates_identifiers,
+    packages().with(Style::psql()).with(Layout::fit(40)),
+    " id | path            | description     "
+    "----+-----------------+-----------------"
+    " 1  | /usr/local/b... | Renders tables  "
+    "    |                 | in a terminal   "
+    " 2  | /etc/tabled/... | Configuration   "
+    "    |                 | of a renderer   "
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    fit_wraps_colored_prose,
+    Table::new([("1", "\u{1b}[31mRenders tables in a terminal\u{1b}[39m")])
+        .with(Style::psql())
+        .with(Layout::fit(22)),
+    " &str | &str          "
+    "------+---------------"
+    " 1    | \u{1b}[31mRenders \u{1b}[39m      "
+    "      | \u{1b}[31mtables in a \u{1b}[39m  "
+    "      | \u{1b}[31mterminal\u{1b}[39m      "
+);
+
+test_table!(
+    fit_keeps_minimum_width,
+    packages().with(Style::psql()).with(Layout::fit(20)),
+    " id | path  | descr "
+    "    |       | iptio "
+    "    |       | n     "
+    "----+-------+-------"
+    " 1  | /u... | Rende "
+    "    |       | rs ta "
+    "    |       | bles  "
+    "    |       | in a  "
+    "    |       | termi "
+    "    |       | nal   "
+    " 2  | /e... | Confi "
+    "    |       | gurat "
+    "    |       | ion   "
+    "    |       | of a  "
+    "    |       | rende "
+    "    |       | rer   "
+);
+
+test_table!(
+    fit_column_minimum_width,
+    packages().with(Style::psql()).with(Layout::fit(40).column_min_width(2, 12)),
+    " id | path            | description     "
+    "----+-----------------+-----------------"
+    " 1  | /usr/local/b... | Renders tables  "
+    "    |                 | in a terminal   "
+    " 2  | /etc/tabled/... | Configuration   "
+    "    |                 | of a renderer   "
+);
+
+#[cfg(not(feature = "ansi"))]
+test_table!(
+    fit_without_fallback,
+    crates().build().with(Style::psql()).with(Layout::fit(30).fallback(false)),
+    " name  | ve... | li... | re... | descr "
+    "       |       |       |       | iptio "
+    "       |       |       |       | n     "
+    "-------+-------+-------+-------+-------"
+    " ta... | 0.... | MIT   | gi... | Prett "
+    "       |       |       |       | y     "
+    "       |       |       |       | print "
+    "       |       |       |       | table "
+    "       |       |       |       | s     "
+);
+
+test_table!(
+    fit_rotates_table,
+    crates().build().with(Style::psql()).with(Layout::fit(30)),
+    " name        | tabled         "
+    "-------------+----------------"
+    " version     | 0.15.0         "
+    " license     | MIT            "
+    " repository  | github.com/... "
+    " description | Pretty prin... "
+);
+
+test_table!(
+    fit_splits_table,
+    {
+        let mut builder = crates();
+        builder.push_record(["papergrid", "0.11.0", "MIT", "github.com/zhiburt/tabled", "Core of tabled"]);
+        builder.push_record(["csv_to_table", "0.3.0", "MIT", "github.com/zhiburt/tabled", "Convert csv"]);
+        builder.build().with(Style::psql()).with(Layout::fit(30)).to_string()
+    },
+    " name    | version  | license "
+    "---------+----------+---------"
+    " tabled  | 0.15.0   | MIT     "
+    " pape... | 0.11.0   | MIT     "
+    " csv_... | 0.3.0    | MIT     "
+    " repo... | descr... |         "
+    " gith... | Prett... |         "
+    " gith... | Core ... |         "
+    " gith... | Conve... |         "
+);
@@ -1,15 +1,19 @@
+mod aggregate_test;
 mod alignment_test;
 mod color_test;
 mod colorization;
 mod column_names_test;
 mod concat_test;
+mod direction_test;
 mod disable_test;
 mod duplicate_test;
 mod extract_test;
+mod filter_test;
 mod format_test;
 mod formatting_test;
 mod height_test;
 mod highlingt_test;
+mod hyperlink_test;
 mod layout_test;
 mod margin_test;
 mod merge_test;
@@ -19,6 +23,7 @@ mod render_settings;
 mod reverse_test;
 mod rotate_test;
 mod shadow_test;
+mod sort_test;
 mod span_test;
 mod split_test;
 mod style_test;
@@ -0,0 +1,196 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        location::Locator,
+        object::{Cell, Columns},
+        sort::Sort,
+        Alignment, Modify, Panel, Span, Style,
+    },
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+fn people() -> Table {
+    let mut builder = Builder::default();
+    builder.push_record(["name", "age", "city"]);
+    builder.push_record(["Tom", "27", "Minsk"]);
+    builder.push_record(["Ann", "9", "Grodno"]);
+    builder.push_record(["Bob", "41", "Brest"]);
+    builder.push_record(["Eve", "9", "Gomel"]);
+    builder.build()
+}
+
+test_table!(
+    sort_empty,
+    Matrix::empty().with(Sort::column(Columns::first())),
+    ""
+);
+
+test_table!(
+    sort_lexicographic,
+    people().with(Style::psql()).with(Sort::column(Columns::first())),
+    " name | age | city   "
+    "------+-----+--------"
+    " Ann  | 9   | Grodno "
+    " Bob  | 41  | Brest  "
+    " Eve  | 9   | Gomel  "
+    " Tom  | 27  | Minsk  "
+);
+
+test_table!(
+    sort_lexicographic_descending,
+    people().with(Style::psql()).with(Sort::column(Locator::column("city")).descending()),
+    " name | age | city   "
+    "------+-----+--------"
+    " Tom  | 27  | Minsk  "
+    " Ann  | 9   | Grodno "
+    " Eve  | 9   | Gomel  "
+    " Bob  | 41  | Brest  "
+);
+
+test_table!(
+    sort_lexicographic_numbers,
+    people().with(Style::psql()).with(Sort::column(Locator::column("age"))),
+    " name | age | city   "
+    "------+-----+--------"
+    " Tom  | 27  | Minsk  "
+    " Bob  | 41  | Brest  "
+    " Ann  | 9   | Grodno "
+    " Eve  | 9   | Gomel  "
+);
+
+test_table!(
+    sort_numeric_is_stable,
+    people().with(Style::psql()).with(Sort::column(Locator::column("age")).numeric()),
+    " name | age | city   "
+    "------+-----+--------"
+    " Ann  | 9   | Grodno "
+    " Eve  | 9   | Gomel  "
+    " Tom  | 27  | Minsk  "
+    " Bob  | 41  | Brest  "
+);
+
+test_table!(
+    sort_numeric_descending,
+    people().with(Style::psql()).with(Sort::column(Locator::column("age")).numeric().descending()),
+    " name | age | city   "
+    "------+-----+--------"
+    " Bob  | 41  | Brest  "
+    " Tom  | 27  | Minsk  "
+    " Ann  | 9   | Grodno "
+    " Eve  | 9   | Gomel  "
+);
+
+test_table!(
+    sort_numeric_descending_keeps_text_last,
+    Table::new(["x", "2", "10"])
+        .with(Style::psql())
+        .with(Sort::column(Columns::first()).numeric().descending()),
+    " &str "
+    "------"
+    " 10   "
+    " 2    "
+    " x    "
+);
+
+test_table!(
+    sort_custom_comparator,
+    people()
+        .with(Style::psql())
+        .with(Sort::column(Columns::last()).by(|a: &str, b: &str| a.len().cmp(&b.len()))),
+    " name | age | city   "
+    "------+-----+--------"
+    " Tom  | 27  | Minsk  "
+    " Bob  | 41  | Brest  "
+    " Eve  | 9   | Gomel  "
+    " Ann  | 9   | Grodno "
+);
+
+test_table!(
+    sort_without_header,
+    people().with(Style::psql()).with(Sort::column(Columns::first()).header(0)),
+    " Ann  | 9   | Grodno "
+    "------+-----+--------"
+    " Bob  | 41  | Brest  "
+    " Eve  | 9   | Gomel  "
+    " Tom  | 27  | Minsk  "
+    " name | age | city   "
+);
+
+test_table!(
+    sort_unknown_column,
+    people().with(Style::psql()).with(Sort::column(Locator::column("unknown"))),
+    " name | age | city   "
+    "------+-----+--------"
+    " Tom  | 27  | Minsk  "
+    " Ann  | 9   | Grodno "
+    " Bob  | 41  | Brest  "
+    " Eve  | 9   | Gomel  "
+);
+
+test_table!(
+    sort_keeps_panels,
+    people()
+        .with(Panel::header("People"))
+        .with(Panel::footer("Total: 4"))
+        .with(Sort::column(Columns::first()))
+        .with(Style::psql()),
+    " People              "
+    "------+-----+--------"
+    " name | age | city   "
+    " Ann  | 9   | Grodno "
+    " Bob  | 41  | Brest  "
+    " Eve  | 9   | Gomel  "
+    " Tom  | 27  | Minsk  "
+    " Total: 4            "
+);
+
+test_table!(
+    sort_sorts_rows_between_panels_separately,
+    people()
+        .with(Panel::horizontal(3, "---"))
+        .with(Sort::column(Columns::first()))
+        .with(Style::psql()),
+    " name | age | city   "
+    "------+-----+--------"
+    " Ann  | 9   | Grodno "
+    " Tom  | 27  | Minsk  "
+    " ---                 "
+    " Bob  | 41  | Brest  "
+    " Eve  | 9   | Gomel  "
+);
+
+test_table!(
+    sort_moves_cell_settings,
+    people()
+        .with(Modify::new(Columns::new(..)).with(Alignment::center()))
+        .with(Modify::new(Cell::new(1, 0)).with(Alignment::left()))
+        .with(Modify::new(Cell::new(3, 2)).with(Alignment::right()))
+        .with(Modify::new(Cell::new(2, 0)).with(Span::column(2)))
+        .with(Sort::column(Columns::last()))
+        .with(Style::psql()),
+    " name | age |  city  "
+    "------+-----+--------"
+    " Bob  | 41  |  Brest "
+    " Eve  |  9  | Gomel  "
+    "    Ann     | Grodno "
+    " Tom  | 27  | Minsk  "
+);
+
+test_table!(
+    sort_moves_row_spans_together,
+    people()
+        .with(Modify::new(Cell::new(2, 2)).with(Span::row(2)))
+        .with(Sort::column(Columns::first()).descending())
+        .with(Style::psql()),
+    " name | age | city   "
+    "------+-----+--------"
+    " Tom  | 27  | Minsk  "
+    " Eve  | 9   | Gomel  "
+    " Ann  | 9   | Grodno "
+    " Bob  | 41  |        "
+);
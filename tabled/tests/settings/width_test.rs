@@ -517,6 +517,7 @@ fn max_width_with_emoji() {
         .with(Modify::new(Segment::all()).with(Width::truncate(6).suffix("...")))
         .to_string();
 
+    #[cfg(not(feature = "grapheme"))]
     assert_eq!(
         table,
         static_table!(
@@ -527,6 +528,17 @@ fn max_width_with_emoji() {
             "| 🚴�... |"
         )
     );
+    #[cfg(feature = "grapheme")]
+    assert_eq!(
+        table,
+        static_table!(
+            "|  &str  |"
+            "|--------|"
+            "|   🤠   |"
+            "| 😳�... |"
+            "| 🚴🏻\u{200d}♀\u{fe0f}�... |"
+        )
+    );
 }
 
 #[cfg(feature = "ansi")]
@@ -2744,22 +2756,24 @@ mod derived {
              +-------+-------+"
         );
 
-        // if there's more text than a link it will be ignored
+        // a link is kept when there is other text
         let text = format!(
             "{} :link",
             format_osc8_hyperlink("https://www.debian.org/", "Debian"),
         );
         assert_eq!(
             table(&text),
-            "+-------+-------+\n\
-             | name  | is_hy |\n\
-             |       | perli |\n\
-             |       | nk    |\n\
-             +-------+-------+\n\
-             | Debia | true  |\n\
-             | n     |       |\n\
-             | :link |       |\n\
-             +-------+-------+"
+            static_table!(
+                "+-------+-------+"
+                "| name  | is_hy |"
+                "|       | perli |"
+                "|       | nk    |"
+                "+-------+-------+"
+                "| \u{1b}]8;;https://www.debian.org/\u{1b}\\Debia\u{1b}]8;;\u{1b}\\ | true  |"
+                "| \u{1b}]8;;https://www.debian.org/\u{1b}\\n\u{1b}]8;;\u{1b}\\     |       |"
+                "| :link |       |"
+                "+-------+-------+"
+            )
         );
 
         let text = format!(
@@ -2775,14 +2789,14 @@ mod derived {
                 "|       | perli |"
                 "|       | nk    |"
                 "+-------+-------+"
-                "| asd D | true  |"
-                "| ebian |       |"
+                "| asd \u{1b}]8;;https://www.debian.org/\u{1b}\\D\u{1b}]8;;\u{1b}\\ | true  |"
+                "| \u{1b}]8;;https://www.debian.org/\u{1b}\\ebian\u{1b}]8;;\u{1b}\\ |       |"
                 "|  2    |       |"
                 "| links |       |"
                 "|  in a |       |"
                 "|  stri |       |"
-                "| ng De |       |"
-                "| bian  |       |"
+                "| ng \u{1b}]8;;https://www.wikipedia.org/\u{1b}\\De\u{1b}]8;;\u{1b}\\ |       |"
+                "| \u{1b}]8;;https://www.wikipedia.org/\u{1b}\\bian\u{1b}]8;;\u{1b}\\  |       |"
                 "+-------+-------+"
             )
         );
@@ -2829,7 +2843,7 @@ mod derived {
             )
         );
 
-        // if there's more text than a link it will be ignored
+        // a link is kept when there is other text
         let text = format!(
             "{} :link",
             format_osc8_hyperlink("https://www.debian.org/", "Debian"),
@@ -2841,7 +2855,7 @@ mod derived {
                 "| name   | is_hyp |"
                 "|        | erlink |"
                 "+--------+--------+"
-                "| Debian | true   |"
+                "| \u{1b}]8;;https://www.debian.org/\u{1b}\\Debian\u{1b}]8;;\u{1b}\\ | true   |"
                 "|  :link |        |"
                 "+--------+--------+"
             )
@@ -2860,15 +2874,79 @@ mod derived {
                 "|        | erlink |"
                 "+--------+--------+"
                 "| asd    | true   |"
-                "| Debian |        |"
+                "| \u{1b}]8;;https://www.debian.org/\u{1b}\\Debian\u{1b}]8;;\u{1b}\\ |        |"
                 "|  2     |        |"
                 "| links  |        |"
                 "| in a   |        |"
                 "| string |        |"
                 "|        |        |"
-                "| Debian |        |"
+                "| \u{1b}]8;;https://www.wikipedia.org/\u{1b}\\Debian\u{1b}]8;;\u{1b}\\ |        |"
                 "+--------+--------+"
             )
         );
     }
 }
+
+#[cfg(feature = "grapheme")]
+mod grapheme {
+    use tabled::{
+        settings::{object::Columns, Style, Width},
+        Table,
+    };
+    use testing_table::test_table;
+
+    const FAMILY: &str = "👨\u{200d}👩\u{200d}👧";
+
+    test_table!(
+        grapheme_table,
+        Table::new([(FAMILY, "family"), ("🇺🇦", "flag"), ("नमस्ते", "hello")]).with(Style::modern()),
+        "┌──────┬────────┐"
+        "│ &str │ &str   │"
+        "├──────┼────────┤"
+        "│ 👨‍👩‍👧   │ family │"
+        "├──────┼────────┤"
+        "│ 🇺🇦   │ flag   │"
+        "├──────┼────────┤"
+        "│ नमस्ते │ hello  │"
+        "└──────┴────────┘"
+    );
+
+    test_table!(
+        grapheme_truncate,
+        Table::new([format!("{FAMILY}{FAMILY}{FAMILY}")])
+            .with(Style::modern())
+            .modify(Columns::first(), Width::truncate(5)),
+        "┌───────┐"
+        "│ Strin │"
+        "├───────┤"
+        "│ 👨‍👩‍👧👨‍👩‍👧� │"
+        "└───────┘"
+    );
+
+    test_table!(
+        grapheme_wrap,
+        Table::new([format!("{FAMILY}{FAMILY}{FAMILY}")])
+            .with(Style::modern())
+            .modify(Columns::first(), Width::wrap(4)),
+        "┌──────┐"
+        "│ Stri │"
+        "│ ng   │"
+        "├──────┤"
+        "│ 👨‍👩‍👧👨‍👩‍👧 │"
+        "│ 👨‍👩‍👧   │"
+        "└──────┘"
+    );
+
+    test_table!(
+        grapheme_wrap_keep_words,
+        Table::new(["नमस्ते नमस्ते"])
+            .with(Style::modern())
+            .modify(Columns::first(), Width::wrap(5).keep_words(true)),
+        "┌───────┐"
+        "│ &str  │"
+        "├───────┤"
+        "│ नमस्ते  │"
+        "│ नमस्ते  │"
+        "└───────┘"
+    );
+}
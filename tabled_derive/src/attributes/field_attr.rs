@@ -1,6 +1,7 @@
-use syn::{Attribute, LitInt};
+use syn::{Attribute, LitInt, LitStr, WherePredicate};
 
 use crate::{
+    attributes::parse_bound,
     casing_style::CasingStyle,
     error::Error,
     parse::field_attr::{parse_field_attributes, FieldAttr, FieldAttrKind},
@@ -18,6 +19,18 @@ pub struct FieldAttributes {
     pub order: Option<usize>,
     pub format: Option<String>,
     pub format_with_args: Option<Vec<FormatArg>>,
+    pub datetime: bool,
+    pub alignment: Option<String>,
+    pub max_width: Option<usize>,
+    pub color: Option<String>,
+    pub bound: Option<Vec<WherePredicate>>,
+    pub display_option: Option<String>,
+    pub join: Option<String>,
+    pub nested_table: bool,
+    pub group: Option<String>,
+    pub skip_if_all: Option<String>,
+    pub display_if: Option<String>,
+    pub header_key: Option<String>,
 }
 
 pub struct FormatArg {
@@ -84,6 +97,26 @@ impl FieldAttributes {
                 }
             }
             FieldAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
+            FieldAttrKind::DateTime(b) => {
+                if b.value {
+                    self.datetime = true;
+                }
+            }
+            FieldAttrKind::Align(lit) => self.alignment = Some(parse_alignment(&lit)?),
+            FieldAttrKind::MaxWidth(value) => self.max_width = Some(lit_int_to_usize(&value)?),
+            FieldAttrKind::Color(lit) => self.color = Some(parse_color(&lit)?),
+            FieldAttrKind::Bound(lit) => self.bound = Some(parse_bound(&lit)?),
+            FieldAttrKind::DisplayOption(lit) => self.display_option = Some(lit.value()),
+            FieldAttrKind::Join(lit) => self.join = Some(lit.value()),
+            FieldAttrKind::NestedTable(b) => {
+                if b.value {
+                    self.nested_table = true;
+                }
+            }
+            FieldAttrKind::Group(lit) => self.group = Some(lit.value()),
+            FieldAttrKind::SkipIfAll(lit) => self.skip_if_all = Some(lit.value()),
+            FieldAttrKind::DisplayIf(lit) => self.display_if = Some(lit.value()),
+            FieldAttrKind::HeaderKey(lit) => self.header_key = Some(lit.value()),
         }
 
         Ok(())
@@ -99,3 +132,48 @@ fn lit_int_to_usize(value: &LitInt) -> Result<usize, Error> {
         )
     })
 }
+
+fn parse_alignment(lit: &LitStr) -> Result<String, Error> {
+    let value = lit.value();
+    match value.as_str() {
+        "left" | "center" | "right" => Ok(value),
+        _ => Err(Error::new(
+            format!("unexpected alignment {value:?}"),
+            lit.span(),
+            Some(String::from(
+                "expected one of \"left\", \"center\" or \"right\"",
+            )),
+        )),
+    }
+}
+
+/// Parses a color name like `red`, `bright_blue` or `bg_green`
+/// into a name of a `Color` constant.
+fn parse_color(lit: &LitStr) -> Result<String, Error> {
+    const COLORS: [&str; 8] = [
+        "black", "blue", "cyan", "green", "magenta", "red", "white", "yellow",
+    ];
+
+    let value = lit.value();
+    if value == "bold" {
+        return Ok(String::from("BOLD"));
+    }
+
+    let (prefix, name) = match value.strip_prefix("bg_") {
+        Some(name) => ("BG", name),
+        None => ("FG", value.as_str()),
+    };
+
+    let color = name.strip_prefix("bright_").unwrap_or(name);
+    if !COLORS.contains(&color) {
+        return Err(Error::new(
+            format!("unexpected color {value:?}"),
+            lit.span(),
+            Some(String::from(
+                "expected a color like \"red\", \"bright_red\", \"bg_red\" or \"bold\"",
+            )),
+        ));
+    }
+
+    Ok(format!("{}_{}", prefix, name.to_uppercase()))
+}
@@ -2,4 +2,4 @@ mod field_attr;
 mod type_attr;
 
 pub use field_attr::{FieldAttributes, FormatArg};
-pub use type_attr::TypeAttributes;
+pub use type_attr::{parse_bound, TypeAttributes};
@@ -1,4 +1,4 @@
-use syn::Attribute;
+use syn::{Attribute, LitStr, WherePredicate};
 
 use crate::{
     casing_style::CasingStyle,
@@ -6,12 +6,17 @@ use crate::{
     parse::type_attr::{parse_type_attributes, TypeAttr, TypeAttrKind},
 };
 
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct TypeAttributes {
     pub rename_all: Option<CasingStyle>,
     pub inline: bool,
     pub inline_value: Option<String>,
     pub crate_name: Option<String>,
+    pub bound: Option<Vec<WherePredicate>>,
+    pub style: Option<String>,
+    pub header: Option<String>,
+    pub footer_fn: Option<String>,
+    pub tagged: bool,
 }
 
 impl TypeAttributes {
@@ -53,8 +58,80 @@ impl TypeAttributes {
             TypeAttrKind::RenameAll(lit) => {
                 self.rename_all = Some(CasingStyle::from_lit(&lit)?);
             }
+            TypeAttrKind::Bound(lit) => self.bound = Some(parse_bound(&lit)?),
+            TypeAttrKind::Style(lit) => self.style = Some(parse_style(&lit)?),
+            TypeAttrKind::Header(lit) => self.header = Some(lit.value()),
+            TypeAttrKind::FooterFn(lit) => self.footer_fn = Some(lit.value()),
+            TypeAttrKind::EnumMode(lit) => self.tagged = parse_enum_mode(&lit)?,
         }
 
         Ok(())
     }
+
+    /// Checks whether any of table-wide attributes is set.
+    pub fn has_presentation(&self) -> bool {
+        self.style.is_some() || self.header.is_some() || self.footer_fn.is_some()
+    }
+}
+
+const STYLES: &[&str] = &[
+    "empty",
+    "blank",
+    "ascii",
+    "ascii_rounded",
+    "psql",
+    "markdown",
+    "modern",
+    "modern_rounded",
+    "sharp",
+    "rounded",
+    "extended",
+    "dots",
+    "re_structured_text",
+];
+
+/// Parses a name of a built-in style, which is a name of a `Style` constructor.
+fn parse_style(lit: &LitStr) -> Result<String, Error> {
+    let value = lit.value();
+    if STYLES.contains(&value.as_str()) {
+        return Ok(value);
+    }
+
+    Err(Error::new(
+        format!("unexpected style {value:?}"),
+        lit.span(),
+        Some(format!("expected one of {}", STYLES.join(", "))),
+    ))
+}
+
+/// Parses an enum mode, returning whether it's a tagged one.
+fn parse_enum_mode(lit: &LitStr) -> Result<bool, Error> {
+    match lit.value().as_str() {
+        "flags" => Ok(false),
+        "tagged" => Ok(true),
+        value => Err(Error::new(
+            format!("unexpected enum mode {value:?}"),
+            lit.span(),
+            Some(String::from("expected one of \"flags\" or \"tagged\"")),
+        )),
+    }
+}
+
+/// Parses a list of where predicates like `T: Display, U: Tabled`.
+pub fn parse_bound(lit: &LitStr) -> Result<Vec<WherePredicate>, Error> {
+    let value = lit.value();
+    if value.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let clause = format!("where {value}");
+    let clause = syn::parse_str::<syn::WhereClause>(&clause).map_err(|err| {
+        Error::new(
+            format!("failed to parse a bound {value:?}; {err}"),
+            lit.span(),
+            None,
+        )
+    })?;
+
+    Ok(clause.predicates.into_iter().collect())
 }
@@ -13,12 +13,12 @@ mod parse;
 use attributes::FormatArg;
 use proc_macro2::TokenStream;
 use proc_macro_error::proc_macro_error;
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use std::{collections::HashMap, str};
 use syn::visit_mut::VisitMut;
 use syn::{
     parse_macro_input, token, Data, DataEnum, DataStruct, DeriveInput, ExprPath, Field, Fields,
-    Ident, Index, PathSegment, Type, Variant,
+    Ident, Index, PathSegment, Type, Variant, WhereClause,
 };
 
 use crate::attributes::{FieldAttributes, TypeAttributes};
@@ -34,6 +34,22 @@ pub fn tabled(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     proc_macro::TokenStream::from(ast)
 }
 
+#[proc_macro_derive(TabledValues, attributes(tabled))]
+#[proc_macro_error]
+pub fn tabled_values(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ast = impl_tabled_values(&input);
+    proc_macro::TokenStream::from(ast)
+}
+
+#[proc_macro_derive(FromTable, attributes(tabled))]
+#[proc_macro_error]
+pub fn from_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ast = impl_from_table(&input);
+    proc_macro::TokenStream::from(ast)
+}
+
 fn impl_tabled(ast: &DeriveInput) -> TokenStream {
     let attrs = TypeAttributes::parse(&ast.attrs)
         .map_err(error::abort)
@@ -50,8 +66,112 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
     let fields = info.values;
     let headers = info.headers;
 
+    let crate_path = get_crate_path(&attrs).map_err(error::abort).unwrap();
+    let column_settings = collect_column_settings(ast, &attrs, &crate_path)
+        .map_err(error::abort)
+        .unwrap()
+        .map(|settings| {
+            let settings_path = create_path(
+                create_path(crate_path.clone(), "settings"),
+                "ColumnSettings",
+            );
+            quote! {
+                fn column_settings() -> Vec<#settings_path> {
+                    #settings
+                }
+            }
+        });
+
+    let column_groups = collect_column_labels(ast, &attrs, &crate_path, ColumnLabel::Group)
+        .map_err(error::abort)
+        .unwrap()
+        .map(|groups| {
+            quote! {
+                fn column_groups() -> Vec<Option<::std::borrow::Cow<'static, str>>> {
+                    #groups
+                }
+            }
+        });
+
+    let header_keys = collect_column_labels(ast, &attrs, &crate_path, ColumnLabel::HeaderKey)
+        .map_err(error::abort)
+        .unwrap()
+        .map(|keys| {
+            quote! {
+                fn header_keys() -> Vec<Option<::std::borrow::Cow<'static, str>>> {
+                    #keys
+                }
+            }
+        });
+
+    let where_clause = create_where_clause(ast, &attrs, |attr| {
+        if attr.inline {
+            Some(FieldBound::Field(quote!(#tabled_trait_path)))
+        } else if attr.display_with.is_some() || attr.format.is_some() {
+            None
+        } else if attr.display_option.is_some() || attr.join.is_some() {
+            Some(FieldBound::Item(quote!(::std::fmt::Display)))
+        } else if attr.nested_table {
+            Some(FieldBound::Item(quote!(#tabled_trait_path)))
+        } else {
+            Some(FieldBound::Field(quote!(::std::fmt::Display)))
+        }
+    })
+    .map_err(error::abort)
+    .unwrap();
+
     let name = &ast.ident;
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+
+    let hidden_columns = collect_hidden_columns(ast, &attrs, &crate_path)
+        .map_err(error::abort)
+        .unwrap()
+        .map(|hidden| {
+            let filter_path = create_path(
+                create_path(create_path(crate_path.clone(), "settings"), "filter"),
+                "Filter",
+            );
+            quote! {
+                let hidden: Vec<bool> = #hidden;
+                if hidden.iter().any(|&is_hidden| is_hidden) {
+                    let _ = table.with(#filter_path::columns(|col, _| {
+                        !hidden.get(col).copied().unwrap_or(false)
+                    }));
+                }
+            }
+        });
+
+    let presentation_trait_path = create_path(crate_path.clone(), "TabledPresentation");
+    let (apply, presentation) = if attrs.has_presentation() {
+        let apply = quote! {
+            <Self as #presentation_trait_path>::apply(table, rows);
+        };
+
+        let methods = presentation_methods(&attrs, &crate_path);
+        let presentation = quote! {
+            impl #impl_generics #presentation_trait_path for #name #ty_generics #where_clause {
+                #methods
+            }
+        };
+
+        (Some(apply), Some(presentation))
+    } else {
+        (None, None)
+    };
+
+    let present = if hidden_columns.is_some() || apply.is_some() {
+        let table_path = create_path(crate_path.clone(), "Table");
+        Some(quote! {
+            const HAS_PRESENTATION: bool = true;
+
+            fn present(table: &mut #table_path, rows: &[&Self]) {
+                #hidden_columns
+                #apply
+            }
+        })
+    } else {
+        None
+    };
 
     let expanded = quote! {
         impl #impl_generics #tabled_trait_path for #name #ty_generics #where_clause {
@@ -64,12 +184,425 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
             fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
                 #headers
             }
+
+            #column_settings
+
+            #column_groups
+
+            #header_keys
+
+            #present
+        }
+
+        #presentation
+    };
+
+    expanded
+}
+
+fn presentation_methods(attrs: &TypeAttributes, crate_path: &ExprPath) -> TokenStream {
+    let mut methods = TokenStream::new();
+
+    if let Some(style) = &attrs.style {
+        let settings_path = create_path(crate_path.clone(), "settings");
+        let theme_path = create_path(create_path(settings_path.clone(), "themes"), "Theme");
+        let style_path = create_path(settings_path, "Style");
+        let style = Ident::new(style, proc_macro2::Span::call_site());
+
+        methods.extend(quote! {
+            fn style() -> Option<#theme_path> {
+                Some(#theme_path::from_style(#style_path::#style()))
+            }
+        });
+    }
+
+    if let Some(header) = &attrs.header {
+        methods.extend(quote! {
+            fn header() -> Option<::std::borrow::Cow<'static, str>> {
+                Some(::std::borrow::Cow::Borrowed(#header))
+            }
+        });
+    }
+
+    if let Some(footer_fn) = &attrs.footer_fn {
+        let call = use_function(&quote!(rows), footer_fn);
+
+        methods.extend(quote! {
+            fn footer(rows: &[&Self]) -> Option<String> {
+                Some(::std::string::ToString::to_string(&#call))
+            }
+        });
+    }
+
+    methods
+}
+
+fn impl_tabled_values(ast: &DeriveInput) -> TokenStream {
+    let attrs = TypeAttributes::parse(&ast.attrs)
+        .map_err(error::abort)
+        .unwrap();
+
+    let crate_path = get_crate_path(&attrs).map_err(error::abort).unwrap();
+    let values_trait_path = create_path(crate_path.clone(), "TabledValues");
+    let value_path = create_path(crate_path.clone(), "TabledValue");
+
+    let values = collect_values(ast, &attrs, &crate_path)
+        .map_err(error::abort)
+        .unwrap();
+
+    let to_value_path = create_path(crate_path.clone(), "ToTabledValue");
+    let tabled_trait_path = create_path(crate_path.clone(), "Tabled");
+    let where_clause = create_where_clause(ast, &attrs, |attr| {
+        if attr.inline {
+            Some(FieldBound::Field(quote!(#values_trait_path)))
+        } else if attr.display_with.is_some() || attr.format.is_some() {
+            None
+        } else if attr.join.is_some() {
+            Some(FieldBound::Item(quote!(::std::fmt::Display)))
+        } else if attr.nested_table {
+            Some(FieldBound::Item(quote!(#tabled_trait_path)))
+        } else if attr.datetime {
+            Some(FieldBound::Field(quote!(::std::fmt::Display)))
+        } else {
+            Some(FieldBound::Field(quote!(#to_value_path)))
+        }
+    })
+    .map_err(error::abort)
+    .unwrap();
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+
+    // `Tabled` is a supertrait so its bounds must be satisfied as well
+    let where_clause = match where_clause {
+        Some(mut clause) if ast.generics.type_params().next().is_some() => {
+            let tabled_trait_path = create_path(crate_path.clone(), "Tabled");
+            clause
+                .predicates
+                .push(syn::parse_quote!(#name #ty_generics: #tabled_trait_path));
+            Some(clause)
+        }
+        clause => clause,
+    };
+
+    let expanded = quote! {
+        impl #impl_generics #values_trait_path for #name #ty_generics #where_clause {
+            fn values(&self) -> Vec<#value_path<'_>> {
+                #values
+            }
         }
     };
 
     expanded
 }
 
+fn impl_from_table(ast: &DeriveInput) -> TokenStream {
+    let attrs = TypeAttributes::parse(&ast.attrs)
+        .map_err(error::abort)
+        .unwrap();
+
+    let crate_path = get_crate_path(&attrs).map_err(error::abort).unwrap();
+    let from_table_path = create_path(crate_path.clone(), "FromTable");
+    let error_path = create_path(crate_path.clone(), "FromTableError");
+
+    let body = match &ast.data {
+        Data::Struct(data) => from_table_fields(&data.fields, &attrs, &crate_path),
+        _ => Err(Error::new(
+            "FromTable can be derived only for a struct",
+            ast.ident.span(),
+            None,
+        )),
+    }
+    .map_err(error::abort)
+    .unwrap();
+
+    let where_clause = create_where_clause(ast, &attrs, |attr| {
+        if attr.inline {
+            Some(FieldBound::Field(quote!(#from_table_path)))
+        } else if attr.display_option.is_some() || attr.join.is_some() {
+            Some(FieldBound::Item(quote!(::std::str::FromStr)))
+        } else {
+            Some(FieldBound::Field(quote!(::std::str::FromStr)))
+        }
+    })
+    .map_err(error::abort)
+    .unwrap();
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+
+    // `Tabled` is a supertrait so its bounds must be satisfied as well
+    let where_clause = match where_clause {
+        Some(mut clause) if ast.generics.type_params().next().is_some() => {
+            let tabled_trait_path = create_path(crate_path.clone(), "Tabled");
+            clause
+                .predicates
+                .push(syn::parse_quote!(#name #ty_generics: #tabled_trait_path));
+            Some(clause)
+        }
+        clause => clause,
+    };
+
+    quote! {
+        impl #impl_generics #from_table_path for #name #ty_generics #where_clause {
+            fn from_fields(fields: &[&str]) -> Result<Self, #error_path> {
+                #body
+            }
+        }
+    }
+}
+
+/// Returns a body of `FromTable::from_fields`.
+///
+/// Fields are parsed in an order of columns, as an inlined field takes a few of them.
+fn from_table_fields(
+    fields: &Fields,
+    attrs: &TypeAttributes,
+    crate_path: &ExprPath,
+) -> Result<TokenStream, Error> {
+    let error_path = create_path(crate_path.clone(), "FromTableError");
+
+    let mut parsers = Vec::new();
+    for (i, field, attributes) in ordered_fields(fields, attrs)? {
+        let variable = field_variable(i);
+        let parser = field_parser(field, &attributes, crate_path)?;
+        parsers.push(quote! { let #variable = #parser; });
+    }
+
+    let mut members = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let mut attributes = FieldAttributes::parse(&field.attrs)?;
+        merge_attributes(&mut attributes, attrs);
+
+        let member = field.ident.as_ref().map_or_else(
+            || Index::from(i).to_token_stream(),
+            quote::ToTokens::to_token_stream,
+        );
+
+        if attributes.is_ignored {
+            members.push(quote!(#member: ::std::default::Default::default()));
+        } else {
+            let variable = field_variable(i);
+            members.push(quote!(#member: #variable));
+        }
+    }
+
+    Ok(quote! {
+        let mut offset: usize = 0;
+        #(#parsers)*
+        let _ = offset;
+
+        ::std::result::Result::Ok::<Self, #error_path>(Self { #(#members,)* })
+    })
+}
+
+fn field_variable(index: usize) -> Ident {
+    Ident::new(&format!("__field_{index}"), proc_macro2::Span::call_site())
+}
+
+fn field_parser(
+    field: &Field,
+    attr: &FieldAttributes,
+    crate_path: &ExprPath,
+) -> Result<TokenStream, Error> {
+    let error_path = create_path(crate_path.clone(), "FromTableError");
+    let field_type = &field.ty;
+
+    if attr.nested_table {
+        return Err(Error::new(
+            "a nested_table field can't be parsed by FromTable",
+            field
+                .ident
+                .as_ref()
+                .map_or_else(proc_macro2::Span::call_site, Ident::span),
+            Some(String::from("consider to skip the field")),
+        ));
+    }
+
+    if attr.inline {
+        let from_table_path = create_path(crate_path.clone(), "FromTable");
+        let tabled_trait_path = create_path(crate_path.clone(), "Tabled");
+        return Ok(quote! {{
+            let length = <#field_type as #tabled_trait_path>::LENGTH;
+            let cells = fields.get(offset..offset + length).unwrap_or_default();
+            let value = <#field_type as #from_table_path>::from_fields(cells)
+                .map_err(|err| err.shift_column(offset))?;
+            offset += length;
+            value
+        }});
+    }
+
+    let parse = quote! {
+        |text: &str| text.parse().map_err(|_| #error_path::invalid_value(offset, text))
+    };
+
+    let value = if let Some(default) = &attr.display_option {
+        quote! {
+            if text == #default {
+                None
+            } else {
+                Some((#parse)(text)?)
+            }
+        }
+    } else if let Some(separator) = &attr.join {
+        quote! {
+            text.split(#separator)
+                .filter(|text| !text.is_empty())
+                .map(#parse)
+                .collect::<Result<#field_type, #error_path>>()?
+        }
+    } else {
+        quote! {
+            (#parse)(text)?
+        }
+    };
+
+    Ok(quote! {{
+        let text = fields.get(offset).copied().unwrap_or_default();
+        let value = #value;
+        offset += 1;
+        value
+    }})
+}
+
+/// A bound which is required by a field.
+enum FieldBound {
+    /// A bound on a type of a field.
+    Field(TokenStream),
+    /// A bound on a type of items of a field, like `T` of `Option<T>` or `Vec<T>`.
+    Item(TokenStream),
+}
+
+/// Creates a where clause of an implementation.
+///
+/// A bound given by `field_bound` is added for each shown field which type uses a generic type parameter,
+/// unless bounds are set explicitly by `#[tabled(bound = "...")]`.
+fn create_where_clause<F>(
+    ast: &DeriveInput,
+    attrs: &TypeAttributes,
+    field_bound: F,
+) -> Result<Option<WhereClause>, Error>
+where
+    F: Fn(&FieldAttributes) -> Option<FieldBound>,
+{
+    let mut where_clause = ast.generics.where_clause.clone();
+
+    let params = ast
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect::<Vec<_>>();
+
+    let predicates = match &attrs.bound {
+        Some(bound) => bound.clone(),
+        None if params.is_empty() => return Ok(where_clause),
+        None => {
+            let mut predicates = Vec::new();
+            for (field, attributes) in shown_fields(ast, attrs)? {
+                if let Some(bound) = attributes.bound {
+                    predicates.extend(bound);
+                    continue;
+                }
+
+                let (field_type, bound) = match field_bound(&attributes) {
+                    Some(FieldBound::Field(bound)) => (&field.ty, bound),
+                    Some(FieldBound::Item(bound)) => match item_type(&field.ty) {
+                        Some(item_type) => (item_type, bound),
+                        None => continue,
+                    },
+                    None => continue,
+                };
+
+                if has_ident(field_type.to_token_stream(), &params) {
+                    predicates.push(syn::parse_quote!(#field_type: #bound));
+                }
+            }
+
+            predicates
+        }
+    };
+
+    if predicates.is_empty() {
+        return Ok(where_clause);
+    }
+
+    let clause = where_clause.get_or_insert_with(|| WhereClause {
+        where_token: Default::default(),
+        predicates: Default::default(),
+    });
+    clause.predicates.extend(predicates);
+
+    Ok(where_clause)
+}
+
+/// Returns fields which values are shown,
+/// including fields of inlined enum variants.
+fn shown_fields<'a>(
+    ast: &'a DeriveInput,
+    attrs: &TypeAttributes,
+) -> Result<Vec<(&'a Field, FieldAttributes)>, Error> {
+    let fields = match &ast.data {
+        Data::Struct(data) => data.fields.iter().collect::<Vec<_>>(),
+        Data::Enum(_) if attrs.inline => Vec::new(),
+        Data::Enum(data) => {
+            let mut fields = Vec::new();
+            for variant in &data.variants {
+                let attributes = FieldAttributes::parse(&variant.attrs)?;
+                if !attributes.is_ignored && (attributes.inline || attrs.tagged) {
+                    fields.extend(&variant.fields);
+                }
+            }
+
+            fields
+        }
+        Data::Union(_) => return Err(Error::message("Union type isn't supported")),
+    };
+
+    let mut list = Vec::new();
+    for field in fields {
+        let mut attributes = FieldAttributes::parse(&field.attrs)?;
+        merge_attributes(&mut attributes, attrs);
+        if !attributes.is_ignored {
+            list.push((field, attributes));
+        }
+    }
+
+    Ok(list)
+}
+
+/// Returns a type of items of a given type,
+/// like `T` of `Option<T>`, `Vec<T>`, `[T; N]` or `&[T]`.
+fn item_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(path) => {
+            let segment = path.path.segments.last()?;
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => {
+                    args.args.iter().find_map(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            }
+        }
+        Type::Array(array) => Some(&array.elem),
+        Type::Slice(slice) => Some(&slice.elem),
+        Type::Reference(reference) => item_type(&reference.elem),
+        Type::Group(group) => item_type(&group.elem),
+        Type::Paren(paren) => item_type(&paren.elem),
+        _ => None,
+    }
+}
+
+fn has_ident(tokens: TokenStream, idents: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => idents.contains(&&ident),
+        proc_macro2::TokenTree::Group(group) => has_ident(group.stream(), idents),
+        _ => false,
+    })
+}
+
 fn get_tabled_length(
     ast: &DeriveInput,
     attrs: &TypeAttributes,
@@ -80,6 +613,9 @@ fn get_tabled_length(
         Data::Enum(data) => {
             if attrs.inline {
                 Ok(quote! { 1 })
+            } else if attrs.tagged {
+                let length = TaggedEnum::parse(data, attrs)?.length();
+                Ok(quote! { #length })
             } else {
                 get_enum_length(data, trait_path)
             }
@@ -185,27 +721,53 @@ fn info_from_fields(
     header_prefix: &str,
     trait_path: &ExprPath,
 ) -> Result<Impl, Error> {
-    let count_fields = fields.len();
+    let mut headers = Vec::new();
+    let mut values = Vec::new();
+    for (i, field, attributes) in ordered_fields(fields, attrs)? {
+        let header = field_headers(field, i, &attributes, header_prefix, trait_path);
+        headers.push(header);
 
-    let attributes = fields
-        .into_iter()
-        .enumerate()
-        .map(|(i, field)| -> Result<_, Error> {
-            let mut attributes = FieldAttributes::parse(&field.attrs)?;
-            merge_attributes(&mut attributes, attrs);
+        let field_name_result = field_name(i, field);
+        let value = get_field_fields(
+            &field_name_result,
+            &attributes,
+            fields,
+            field_name,
+            trait_path,
+        );
+        values.push(value);
+    }
 
-            Ok((i, field, attributes))
-        });
+    let headers = quote!({
+        let mut out = Vec::new();
+        #(out.extend(#headers);)*
+        out
+    });
 
-    let mut headers = Vec::new();
-    let mut values = Vec::new();
+    let values = quote!({
+        let mut out = Vec::new();
+        #(out.extend(#values);)*
+        out
+    });
+
+    Ok(Impl { headers, values })
+}
+
+/// Returns fields which are not skipped in an order of columns,
+/// together with their indexes in a declaration and their attributes.
+fn ordered_fields<'a>(
+    fields: &'a Fields,
+    attrs: &TypeAttributes,
+) -> Result<Vec<(usize, &'a Field, FieldAttributes)>, Error> {
+    let count_fields = fields.len();
+
+    let mut list = Vec::new();
     let mut reorder = HashMap::new();
+    for (i, field) in fields.iter().enumerate() {
+        let mut attributes = FieldAttributes::parse(&field.attrs)?;
+        merge_attributes(&mut attributes, attrs);
 
-    let mut skipped = 0;
-    for result in attributes {
-        let (i, field, attributes) = result?;
         if attributes.is_ignored {
-            skipped += 1;
             continue;
         }
 
@@ -216,35 +778,26 @@ fn info_from_fields(
                 )));
             }
 
-            reorder.insert(order, i - skipped);
+            reorder.insert(order, list.len());
         }
 
-        let header = field_headers(field, i, &attributes, header_prefix, trait_path);
-        headers.push(header);
-
-        let field_name_result = field_name(i, field);
-        let value = get_field_fields(&field_name_result, &attributes, fields, field_name);
-        values.push(value);
+        list.push((i, field, attributes));
     }
 
-    if !reorder.is_empty() {
-        values = reorder_fields(&reorder, &values);
-        headers = reorder_fields(&reorder, &headers);
+    if reorder.is_empty() {
+        return Ok(list);
     }
 
-    let headers = quote!({
-        let mut out = Vec::new();
-        #(out.extend(#headers);)*
-        out
-    });
+    let indexes = (0..list.len()).collect::<Vec<_>>();
+    let indexes = reorder_fields(&reorder, &indexes);
 
-    let values = quote!({
-        let mut out = Vec::new();
-        #(out.extend(#values);)*
-        out
-    });
+    let mut list = list.into_iter().map(Some).collect::<Vec<_>>();
+    let list = indexes
+        .into_iter()
+        .filter_map(|index| list[index].take())
+        .collect();
 
-    Ok(Impl { headers, values })
+    Ok(list)
 }
 
 fn reorder_fields<T: Clone>(order: &HashMap<usize, usize>, elements: &[T]) -> Vec<T> {
@@ -314,6 +867,7 @@ fn collect_info_enum(
 
             collect_info_enum_inlined(ast, attrs, enum_name)
         }
+        false if attrs.tagged => collect_info_enum_tagged(ast, attrs, trait_path),
         false => _collect_info_enum(ast, attrs, trait_path),
     }
 }
@@ -343,7 +897,15 @@ fn _collect_info_enum(
     let variant_sizes = get_enum_variant_length(ast, trait_path)
         .collect::<Result<Vec<_>, Error>>()?
         .into_iter();
-    let values = values_for_enum(variant_sizes, &variants, trait_path);
+    let value_type = quote!(::std::borrow::Cow<'_, str>);
+    let empty_value = quote!(::std::borrow::Cow::Borrowed(""));
+    let values = values_for_enum(
+        variant_sizes,
+        &variants,
+        trait_path,
+        &value_type,
+        &empty_value,
+    );
 
     let headers = quote! {
         [
@@ -387,6 +949,164 @@ fn collect_info_enum_inlined(
     Ok(Impl { headers, values })
 }
 
+fn collect_info_enum_tagged(
+    ast: &DataEnum,
+    attrs: &TypeAttributes,
+    trait_path: &ExprPath,
+) -> Result<Impl, Error> {
+    let tagged = TaggedEnum::parse(ast, attrs)?;
+
+    let headers = &tagged.headers;
+    let headers = quote! {
+        vec![
+            ::std::borrow::Cow::Borrowed(#TAGGED_KIND_HEADER),
+            #(::std::borrow::Cow::Borrowed(#headers),)*
+        ]
+    };
+
+    let values = tagged.values(
+        |name| quote!(::std::borrow::Cow::Borrowed(#name)),
+        |variant, field| {
+            let field_name = variant_field_name(field.index, field.field);
+            get_field_fields(
+                &field_name,
+                &field.attributes,
+                &variant.fields,
+                variant_field_name,
+                trait_path,
+            )
+        },
+        &quote!(::std::borrow::Cow::Borrowed("")),
+    );
+
+    Ok(Impl { headers, values })
+}
+
+/// A header of a column with a variant name of an enum in a tagged mode.
+const TAGGED_KIND_HEADER: &str = "kind";
+
+/// An enum in a tagged mode, which has a column with a variant name
+/// and a union of columns of all variants.
+///
+/// Fields of different variants with the same header share a column.
+struct TaggedEnum<'a> {
+    headers: Vec<String>,
+    variants: Vec<TaggedVariant<'a>>,
+}
+
+struct TaggedVariant<'a> {
+    variant: &'a Variant,
+    name: String,
+    fields: Vec<TaggedField<'a>>,
+}
+
+struct TaggedField<'a> {
+    column: usize,
+    index: usize,
+    field: &'a Field,
+    attributes: FieldAttributes,
+}
+
+impl<'a> TaggedEnum<'a> {
+    fn parse(ast: &'a DataEnum, attrs: &TypeAttributes) -> Result<Self, Error> {
+        let mut headers: Vec<String> = Vec::new();
+        let mut variants = Vec::new();
+        for variant in reodered_variants(ast)? {
+            let mut attributes = FieldAttributes::parse(&variant.attrs)?;
+            merge_attributes(&mut attributes, attrs);
+            if attributes.is_ignored {
+                continue;
+            }
+
+            let mut fields = Vec::new();
+            for (index, field) in variant.fields.iter().enumerate() {
+                let mut attributes = FieldAttributes::parse(&field.attrs)?;
+                merge_attributes(&mut attributes, attrs);
+                if attributes.is_ignored {
+                    continue;
+                }
+
+                if attributes.inline {
+                    return Err(Error::new(
+                        "an inline field isn't supported in a tagged enum mode",
+                        field
+                            .ident
+                            .as_ref()
+                            .map_or_else(|| variant.ident.span(), Ident::span),
+                        Some(String::from("consider to use display_with instead")),
+                    ));
+                }
+
+                let header = field_header_name(field, &attributes, index);
+                let column = match headers.iter().position(|h| *h == header) {
+                    Some(column) => column,
+                    None => {
+                        headers.push(header);
+                        headers.len() - 1
+                    }
+                };
+
+                fields.push(TaggedField {
+                    column,
+                    index,
+                    field,
+                    attributes,
+                });
+            }
+
+            let name = variant_name(variant, &attributes);
+            variants.push(TaggedVariant {
+                variant,
+                name,
+                fields,
+            });
+        }
+
+        Ok(Self { headers, variants })
+    }
+
+    fn length(&self) -> usize {
+        self.headers.len() + 1
+    }
+
+    /// Returns a body which builds a row out of a matched variant.
+    fn values<K, F>(&self, kind_value: K, field_value: F, empty_value: &TokenStream) -> TokenStream
+    where
+        K: Fn(&str) -> TokenStream,
+        F: Fn(&Variant, &TaggedField<'_>) -> TokenStream,
+    {
+        let length = self.length();
+        let branches = self.variants.iter().map(|variant| {
+            let pattern = match_variant(variant.variant);
+            let kind = kind_value(&variant.name);
+            let fields = variant.fields.iter().map(|field| {
+                let column = field.column + 1;
+                let value = field_value(variant.variant, field);
+                quote! { out[#column] = (#value).remove(0); }
+            });
+
+            quote! {
+                Self::#pattern => {
+                    out[0] = #kind;
+                    #(#fields)*
+                }
+            }
+        });
+
+        quote! {
+            let mut out = vec![#empty_value; #length];
+
+            #[allow(unused_variables)]
+            match &self {
+                #(#branches,)*
+                _ => {} // variant is hidden so we return an empty row
+            };
+
+            out
+        }
+    }
+}
+
 fn info_from_variant(
     variant: &Variant,
     attr: &FieldAttributes,
@@ -468,6 +1188,580 @@ struct Impl {
     values: TokenStream,
 }
 
+/// Returns a body of `Tabled::column_settings`,
+/// or nothing in case there's no settings.
+fn collect_column_settings(
+    ast: &DeriveInput,
+    attrs: &TypeAttributes,
+    crate_path: &ExprPath,
+) -> Result<Option<TokenStream>, Error> {
+    let settings = match &ast.data {
+        Data::Struct(data) => settings_from_fields(&data.fields, attrs, crate_path)?,
+        Data::Enum(_) if attrs.inline => return Ok(None),
+        Data::Enum(data) if attrs.tagged => {
+            let tagged = TaggedEnum::parse(data, attrs)?;
+
+            // a setting of a first field of a column is used
+            let mut list = vec![None; tagged.length()];
+            for field in tagged.variants.iter().flat_map(|v| &v.fields) {
+                let settings = &mut list[field.column + 1];
+                if settings.is_none() {
+                    *settings = column_settings(&field.attributes, crate_path);
+                }
+            }
+
+            list
+        }
+        Data::Enum(data) => {
+            let mut list = Vec::new();
+            for v in reodered_variants(data)? {
+                let mut attributes = FieldAttributes::parse(&v.attrs)?;
+                merge_attributes(&mut attributes, attrs);
+                if attributes.is_ignored {
+                    continue;
+                }
+
+                if attributes.inline {
+                    list.extend(settings_from_fields(&v.fields, attrs, crate_path)?);
+                } else {
+                    list.push(column_settings(&attributes, crate_path));
+                }
+            }
+
+            list
+        }
+        Data::Union(_) => return Err(Error::message("Union type isn't supported")),
+    };
+
+    if settings.iter().all(Option::is_none) {
+        return Ok(None);
+    }
+
+    let settings_path = create_path(
+        create_path(crate_path.clone(), "settings"),
+        "ColumnSettings",
+    );
+    let settings = settings
+        .into_iter()
+        .map(|settings| settings.unwrap_or_else(|| quote!(vec![#settings_path::new()])));
+
+    Ok(Some(quote!({
+        let mut out = Vec::new();
+        #(out.extend(#settings);)*
+        out
+    })))
+}
+
+/// Returns settings of each field, where [`None`] means there's no settings.
+fn settings_from_fields(
+    fields: &Fields,
+    attrs: &TypeAttributes,
+    crate_path: &ExprPath,
+) -> Result<Vec<Option<TokenStream>>, Error> {
+    let tabled_trait_path = create_path(crate_path.clone(), "Tabled");
+    let settings_path = create_path(
+        create_path(crate_path.clone(), "settings"),
+        "ColumnSettings",
+    );
+
+    let mut settings = Vec::new();
+
+    for (_, field, attributes) in ordered_fields(fields, attrs)? {
+        let field_settings = if attributes.inline {
+            let field_type = &field.ty;
+            Some(quote! {{
+                let mut list = <#field_type as #tabled_trait_path>::column_settings();
+                list.resize(<#field_type as #tabled_trait_path>::LENGTH, #settings_path::new());
+                list
+            }})
+        } else {
+            column_settings(&attributes, crate_path)
+        };
+
+        settings.push(field_settings);
+    }
+
+    Ok(settings)
+}
+
+fn column_settings(attr: &FieldAttributes, crate_path: &ExprPath) -> Option<TokenStream> {
+    let settings_path = create_path(
+        create_path(crate_path.clone(), "settings"),
+        "ColumnSettings",
+    );
+
+    let mut settings = quote!(#settings_path::new());
+    let mut is_empty = true;
+
+    if let Some(alignment) = &attr.alignment {
+        let alignment_path = create_path(
+            create_path(create_path(crate_path.clone(), "grid"), "config"),
+            "AlignmentHorizontal",
+        );
+        let alignment = match alignment.as_str() {
+            "center" => quote!(Center),
+            "right" => quote!(Right),
+            _ => quote!(Left),
+        };
+
+        settings.append_all(quote!(.alignment(#alignment_path::#alignment)));
+        is_empty = false;
+    }
+
+    if let Some(width) = attr.max_width {
+        settings.append_all(quote!(.max_width(#width)));
+        is_empty = false;
+    }
+
+    if let Some(color) = &attr.color {
+        let color_path = create_path(create_path(crate_path.clone(), "settings"), "Color");
+        let color = Ident::new(color, proc_macro2::Span::call_site());
+
+        settings.append_all(quote!(.color(#color_path::#color)));
+        is_empty = false;
+    }
+
+    match is_empty {
+        true => None,
+        false => Some(quote!(vec![#settings])),
+    }
+}
+
+/// Returns a body which computes whether each column is hidden by `skip_if_all`,
+/// or nothing in case there's no such columns.
+fn collect_hidden_columns(
+    ast: &DeriveInput,
+    attrs: &TypeAttributes,
+    crate_path: &ExprPath,
+) -> Result<Option<TokenStream>, Error> {
+    let fields = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            for field in data.variants.iter().flat_map(|v| &v.fields) {
+                let attributes = FieldAttributes::parse(&field.attrs)?;
+                if attributes.skip_if_all.is_some() {
+                    return Err(Error::new(
+                        "skip_if_all is supported only for a struct",
+                        field
+                            .ident
+                            .as_ref()
+                            .map_or_else(|| ast.ident.span(), Ident::span),
+                        None,
+                    ));
+                }
+            }
+
+            return Ok(None);
+        }
+        Data::Union(_) => return Err(Error::message("Union type isn't supported")),
+    };
+
+    let tabled_trait_path = create_path(crate_path.clone(), "Tabled");
+
+    let mut hidden = Vec::new();
+    let mut has_hidden = false;
+
+    for (i, field, attributes) in ordered_fields(fields, attrs)? {
+        let length = match attributes.inline {
+            true => {
+                let field_type = &field.ty;
+                quote!(<#field_type as #tabled_trait_path>::LENGTH)
+            }
+            false => quote!(1),
+        };
+
+        let is_hidden = match &attributes.skip_if_all {
+            Some(predicate) => {
+                has_hidden = true;
+
+                let member = field.ident.as_ref().map_or_else(
+                    || Index::from(i).to_token_stream(),
+                    quote::ToTokens::to_token_stream,
+                );
+                let condition = use_function(&quote!(&row.#member), predicate);
+
+                quote!(!rows.is_empty() && rows.iter().all(|row| #condition))
+            }
+            None => quote!(false),
+        };
+
+        hidden.push(quote!(vec![#is_hidden; #length]));
+    }
+
+    if !has_hidden {
+        return Ok(None);
+    }
+
+    Ok(Some(quote!({
+        let mut out = Vec::new();
+        #(out.extend(#hidden);)*
+        out
+    })))
+}
+
+/// A per column label which is declared by a field attribute,
+/// like a group of a column or a key of a header.
+#[derive(Clone, Copy)]
+enum ColumnLabel {
+    Group,
+    HeaderKey,
+}
+
+impl ColumnLabel {
+    fn get(self, attributes: &FieldAttributes) -> Option<&String> {
+        match self {
+            Self::Group => attributes.group.as_ref(),
+            Self::HeaderKey => attributes.header_key.as_ref(),
+        }
+    }
+
+    fn method(self) -> Ident {
+        match self {
+            Self::Group => format_ident!("column_groups"),
+            Self::HeaderKey => format_ident!("header_keys"),
+        }
+    }
+
+    /// Checks whether the label can be spread over all columns of an inlined value,
+    /// which makes sense for a group but not for a key of a header.
+    fn check_inline(self, span: proc_macro2::Span) -> Result<(), Error> {
+        match self {
+            Self::Group => Ok(()),
+            Self::HeaderKey => Err(Error::new(
+                "header_key can't be used together with inline",
+                span,
+                Some(String::from(
+                    "consider to set header_key on fields of the inlined type",
+                )),
+            )),
+        }
+    }
+}
+
+/// Returns a body of `Tabled::column_groups` or `Tabled::header_keys`,
+/// or nothing in case there's no labels.
+fn collect_column_labels(
+    ast: &DeriveInput,
+    attrs: &TypeAttributes,
+    crate_path: &ExprPath,
+    label: ColumnLabel,
+) -> Result<Option<TokenStream>, Error> {
+    let labels = match &ast.data {
+        Data::Struct(data) => labels_from_fields(&data.fields, attrs, crate_path, label)?,
+        Data::Enum(_) if attrs.inline || attrs.tagged => return Ok(None),
+        Data::Enum(data) => {
+            let mut list = Vec::new();
+            for v in reodered_variants(data)? {
+                let mut attributes = FieldAttributes::parse(&v.attrs)?;
+                merge_attributes(&mut attributes, attrs);
+                if attributes.is_ignored {
+                    continue;
+                }
+
+                if attributes.inline {
+                    match label.get(&attributes) {
+                        Some(text) => {
+                            label.check_inline(v.ident.span())?;
+
+                            let length = get_fields_length(
+                                &v.fields,
+                                &create_path(crate_path.clone(), "Tabled"),
+                            )?;
+                            list.push(Some(column_label(text, length)));
+                        }
+                        None => {
+                            list.extend(labels_from_fields(&v.fields, attrs, crate_path, label)?)
+                        }
+                    }
+                } else {
+                    let text = label
+                        .get(&attributes)
+                        .map(|text| column_label(text, quote!(1)));
+                    list.push(text);
+                }
+            }
+
+            list
+        }
+        Data::Union(_) => return Err(Error::message("Union type isn't supported")),
+    };
+
+    if labels.iter().all(Option::is_none) {
+        return Ok(None);
+    }
+
+    let labels = labels
+        .into_iter()
+        .map(|text| text.unwrap_or_else(|| quote!(vec![None])));
+
+    Ok(Some(quote!({
+        let mut out = Vec::new();
+        #(out.extend(#labels);)*
+        out
+    })))
+}
+
+/// Returns labels of each field, where [`None`] means there's no label.
+fn labels_from_fields(
+    fields: &Fields,
+    attrs: &TypeAttributes,
+    crate_path: &ExprPath,
+    label: ColumnLabel,
+) -> Result<Vec<Option<TokenStream>>, Error> {
+    let tabled_trait_path = create_path(crate_path.clone(), "Tabled");
+    let method = label.method();
+
+    let mut labels = Vec::new();
+
+    for (_, field, attributes) in ordered_fields(fields, attrs)? {
+        let field_type = &field.ty;
+        let length = quote!(<#field_type as #tabled_trait_path>::LENGTH);
+        let field_label = match (label.get(&attributes), attributes.inline) {
+            (Some(text), true) => {
+                let span = field
+                    .ident
+                    .as_ref()
+                    .map_or_else(proc_macro2::Span::call_site, Ident::span);
+                label.check_inline(span)?;
+
+                Some(column_label(text, length))
+            }
+            (Some(text), false) => Some(column_label(text, quote!(1))),
+            (None, true) => Some(quote! {{
+                let mut list = <#field_type as #tabled_trait_path>::#method();
+                list.resize(#length, None);
+                list
+            }}),
+            (None, false) => None,
+        };
+
+        labels.push(field_label);
+    }
+
+    Ok(labels)
+}
+
+fn column_label(text: &str, length: TokenStream) -> TokenStream {
+    quote!(vec![Some(::std::borrow::Cow::Borrowed(#text)); #length])
+}
+
+fn collect_values(
+    ast: &DeriveInput,
+    attrs: &TypeAttributes,
+    crate_path: &ExprPath,
+) -> Result<TokenStream, Error> {
+    match &ast.data {
+        Data::Struct(data) => values_from_fields(
+            &data.fields,
+            attrs,
+            struct_field_name,
+            struct_field_ref,
+            crate_path,
+        ),
+        Data::Enum(data) => match attrs.inline {
+            true => values_for_enum_inlined(data, attrs, crate_path),
+            false if attrs.tagged => values_for_enum_tagged(data, attrs, crate_path),
+            false => values_for_enum_variants(data, attrs, crate_path),
+        },
+        Data::Union(_) => Err(Error::message("Union type isn't supported")),
+    }
+}
+
+fn values_from_fields(
+    fields: &Fields,
+    attrs: &TypeAttributes,
+    field_name: FieldNameFn,
+    field_ref: FieldNameFn,
+    crate_path: &ExprPath,
+) -> Result<TokenStream, Error> {
+    let mut values = Vec::new();
+
+    for (i, field, attributes) in ordered_fields(fields, attrs)? {
+        let field_name_result = field_name(i, field);
+        let field_ref_result = field_ref(i, field);
+        let value = get_field_values(
+            &field_name_result,
+            &field_ref_result,
+            &attributes,
+            fields,
+            field_name,
+            crate_path,
+        );
+        values.push(value);
+    }
+
+    Ok(quote!({
+        let mut out = Vec::new();
+        #(out.extend(#values);)*
+        out
+    }))
+}
+
+fn get_field_values(
+    field: &TokenStream,
+    field_ref: &TokenStream,
+    attr: &FieldAttributes,
+    fields: &Fields,
+    field_name: FieldNameFn,
+    crate_path: &ExprPath,
+) -> TokenStream {
+    let values = field_values(field, field_ref, attr, fields, field_name, crate_path);
+    match &attr.display_if {
+        Some(predicate) => {
+            let value_path = create_path(crate_path.clone(), "TabledValue");
+            blank_unless(predicate, field, values, quote!(#value_path::Null))
+        }
+        None => values,
+    }
+}
+
+fn field_values(
+    field: &TokenStream,
+    field_ref: &TokenStream,
+    attr: &FieldAttributes,
+    fields: &Fields,
+    field_name: FieldNameFn,
+    crate_path: &ExprPath,
+) -> TokenStream {
+    let value_path = create_path(crate_path.clone(), "TabledValue");
+
+    if attr.inline {
+        let values_trait_path = create_path(crate_path.clone(), "TabledValues");
+        return quote! { #values_trait_path::values(#field_ref) };
+    }
+
+    let is_string = attr.display_with.is_some()
+        || attr.format.is_some()
+        || attr.join.is_some()
+        || attr.nested_table;
+    if is_string || attr.datetime {
+        let tabled_trait_path = create_path(crate_path.clone(), "Tabled");
+        let fields = field_fields(field, attr, fields, field_name, &tabled_trait_path);
+        let kind = match attr.datetime {
+            true => quote!(DateTime),
+            false => quote!(String),
+        };
+
+        return quote! {
+            #fields.into_iter().map(#value_path::#kind).collect::<Vec<_>>()
+        };
+    }
+
+    let to_value_path = create_path(crate_path.clone(), "ToTabledValue");
+    quote! { vec![#to_value_path::to_tabled_value(#field_ref)] }
+}
+
+fn values_for_enum_tagged(
+    ast: &DataEnum,
+    attrs: &TypeAttributes,
+    crate_path: &ExprPath,
+) -> Result<TokenStream, Error> {
+    let value_path = create_path(crate_path.clone(), "TabledValue");
+    let tagged = TaggedEnum::parse(ast, attrs)?;
+
+    Ok(tagged.values(
+        |name| quote!(#value_path::String(::std::borrow::Cow::Borrowed(#name))),
+        |variant, field| {
+            let field_name = variant_field_name(field.index, field.field);
+            get_field_values(
+                &field_name,
+                &field_name,
+                &field.attributes,
+                &variant.fields,
+                variant_field_name,
+                crate_path,
+            )
+        },
+        &quote!(#value_path::Null),
+    ))
+}
+
+fn values_for_enum_inlined(
+    ast: &DataEnum,
+    attrs: &TypeAttributes,
+    crate_path: &ExprPath,
+) -> Result<TokenStream, Error> {
+    let value_path = create_path(crate_path.clone(), "TabledValue");
+
+    let orderedvariants = reodered_variants(ast)?;
+
+    let mut variants = Vec::new();
+    let mut values = Vec::new();
+    for variant in orderedvariants {
+        let mut attributes = FieldAttributes::parse(&variant.attrs)?;
+        merge_attributes(&mut attributes, attrs);
+        let value = if attributes.is_ignored {
+            quote!(#value_path::Null)
+        } else {
+            let name = variant_name(variant, &attributes);
+            quote!(#value_path::String(::std::borrow::Cow::Borrowed(#name)))
+        };
+
+        variants.push(match_variant(variant));
+        values.push(value);
+    }
+
+    Ok(quote! {
+        #[allow(unused_variables)]
+        match &self {
+            #(Self::#variants => vec![#values],)*
+        }
+    })
+}
+
+fn values_for_enum_variants(
+    ast: &DataEnum,
+    attrs: &TypeAttributes,
+    crate_path: &ExprPath,
+) -> Result<TokenStream, Error> {
+    let tabled_trait_path = create_path(crate_path.clone(), "Tabled");
+    let value_path = create_path(crate_path.clone(), "TabledValue");
+
+    let orderedvariants = reodered_variants(ast)?;
+
+    let mut variants = Vec::new();
+    for v in orderedvariants {
+        let mut attributes = FieldAttributes::parse(&v.attrs)?;
+        merge_attributes(&mut attributes, attrs);
+        if attributes.is_ignored {
+            continue;
+        }
+
+        let values = if attributes.inline {
+            values_from_fields(
+                &v.fields,
+                attrs,
+                variant_field_name,
+                variant_field_name,
+                crate_path,
+            )?
+        } else {
+            let info = info_from_variant(v, &attributes, attrs, &tabled_trait_path)?;
+            let fields = info.values;
+            quote! {
+                #fields.into_iter().map(#value_path::String).collect::<Vec<_>>()
+            }
+        };
+
+        variants.push((v, values));
+    }
+
+    let variant_sizes = get_enum_variant_length(ast, &tabled_trait_path)
+        .collect::<Result<Vec<_>, Error>>()?
+        .into_iter();
+
+    let value_type = quote!(#value_path<'_>);
+    let empty_value = quote!(#value_path::Null);
+
+    Ok(values_for_enum(
+        variant_sizes,
+        &variants,
+        &tabled_trait_path,
+        &value_type,
+        &empty_value,
+    ))
+}
+
 fn get_type_headers(
     field_type: &Type,
     inline_prefix: &str,
@@ -493,6 +1787,43 @@ fn get_field_fields(
     attr: &FieldAttributes,
     fields: &Fields,
     field_name: FieldNameFn,
+    tabled_trait: &ExprPath,
+) -> TokenStream {
+    let values = field_fields(field, attr, fields, field_name, tabled_trait);
+    match &attr.display_if {
+        Some(predicate) => {
+            let empty = quote!(::std::borrow::Cow::Borrowed(""));
+            blank_unless(predicate, field, values, empty)
+        }
+        None => values,
+    }
+}
+
+/// Wraps values of a field so they are replaced by empty ones
+/// in case a predicate returns `false` for the field.
+fn blank_unless(
+    predicate: &str,
+    field: &TokenStream,
+    values: TokenStream,
+    empty: TokenStream,
+) -> TokenStream {
+    let condition = use_function(&quote!(&#field), predicate);
+    quote! {{
+        let values = #values;
+        if #condition {
+            values
+        } else {
+            vec![#empty; values.len()]
+        }
+    }}
+}
+
+fn field_fields(
+    field: &TokenStream,
+    attr: &FieldAttributes,
+    fields: &Fields,
+    field_name: FieldNameFn,
+    tabled_trait: &ExprPath,
 ) -> TokenStream {
     if attr.inline {
         return quote! { #field.fields() };
@@ -540,6 +1871,28 @@ fn get_field_fields(
         };
 
         return quote!(vec![::std::borrow::Cow::Owned(#call)]);
+    } else if let Some(default) = &attr.display_option {
+        return quote! {
+            vec![::std::borrow::Cow::Owned(match &#field {
+                Some(value) => format!("{}", value),
+                None => ::std::string::String::from(#default),
+            })]
+        };
+    } else if let Some(separator) = &attr.join {
+        return quote! {
+            vec![::std::borrow::Cow::Owned(
+                #field
+                    .iter()
+                    .map(|value| format!("{}", value))
+                    .collect::<Vec<_>>()
+                    .join(#separator),
+            )]
+        };
+    } else if attr.nested_table {
+        let table_path = sibling_path(tabled_trait, "Table");
+        return quote! {
+            vec![::std::borrow::Cow::Owned(#table_path::new(#field.iter()).to_string())]
+        };
     }
 
     quote!(vec![::std::borrow::Cow::Owned(format!("{}", #field))])
@@ -587,6 +1940,11 @@ fn struct_field_name(index: usize, field: &Field) -> TokenStream {
     quote!(self.#f)
 }
 
+fn struct_field_ref(index: usize, field: &Field) -> TokenStream {
+    let field = struct_field_name(index, field);
+    quote!(&#field)
+}
+
 fn variant_field_name(index: usize, field: &Field) -> TokenStream {
     match &field.ident {
         Some(indent) => indent.to_token_stream(),
@@ -602,6 +1960,8 @@ fn values_for_enum(
     variant_sizes: impl Iterator<Item = TokenStream>,
     variants: &[(&Variant, TokenStream)],
     tabled_trait: &ExprPath,
+    value_type: &TokenStream,
+    empty_value: &TokenStream,
 ) -> TokenStream {
     let branches = variants.iter().map(|(variant, _)| match_variant(variant));
 
@@ -615,7 +1975,7 @@ fn values_for_enum(
         let branch = quote! {
             Self::#branch => {
                 let offset = offsets[#i];
-                let fields: Vec<::std::borrow::Cow<'_, str>> = #fields;
+                let fields: Vec<#value_type> = #fields;
 
                 for (i, field) in fields.into_iter().enumerate() {
                     out_vec[i+offset] = field;
@@ -640,12 +2000,12 @@ fn values_for_enum(
         }
 
         let size = <Self as #tabled_trait>::LENGTH;
-        let mut out_vec = vec![::std::borrow::Cow::Borrowed(""); size];
+        let mut out_vec = vec![#empty_value; size];
 
         #[allow(unused_variables)]
         match &self {
             #stream
-            _ => return vec![::std::borrow::Cow::Borrowed(""); size], // variant is hidden so we return an empty vector
+            _ => return vec![#empty_value; size], // variant is hidden so we return an empty vector
         };
 
         out_vec
@@ -841,21 +2201,32 @@ fn reodered_variants(ast: &DataEnum) -> Result<Vec<&Variant>, Error> {
 }
 
 fn get_crate_name_expr(attrs: &TypeAttributes) -> Result<ExprPath, Error> {
+    let crate_name = get_crate_path(attrs)?;
+    Ok(create_path(crate_name, "Tabled"))
+}
+
+fn get_crate_path(attrs: &TypeAttributes) -> Result<ExprPath, Error> {
     let crate_name = attrs
         .crate_name
         .clone()
         .unwrap_or_else(|| String::from("::tabled"));
-    let crate_name = parse_crate_name(&crate_name)?;
-    Ok(create_tabled_trait_path(crate_name))
+    parse_crate_name(&crate_name)
 }
 
 fn parse_crate_name(name: &str) -> Result<ExprPath, Error> {
     syn::parse_str(name).map_err(|_| Error::message("unexpected crate attribute type"))
 }
 
-fn create_tabled_trait_path(mut p: ExprPath) -> ExprPath {
+/// Replaces the last segment of a path.
+fn sibling_path(path: &ExprPath, name: &str) -> ExprPath {
+    let mut path = path.clone();
+    let _ = path.path.segments.pop();
+    create_path(path, name)
+}
+
+fn create_path(mut p: ExprPath, name: &str) -> ExprPath {
     p.path.segments.push(PathSegment {
-        ident: Ident::new("Tabled", proc_macro2::Span::call_site()),
+        ident: Ident::new(name, proc_macro2::Span::call_site()),
         arguments: syn::PathArguments::None,
     });
     p
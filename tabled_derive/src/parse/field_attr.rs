@@ -33,6 +33,18 @@ pub enum FieldAttrKind {
     DisplayWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
     Order(LitInt),
     FormatWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
+    DateTime(LitBool),
+    Align(LitStr),
+    MaxWidth(LitInt),
+    Color(LitStr),
+    Bound(LitStr),
+    DisplayOption(LitStr),
+    Join(LitStr),
+    NestedTable(LitBool),
+    Group(LitStr),
+    SkipIfAll(LitStr),
+    DisplayIf(LitStr),
+    HeaderKey(LitStr),
 }
 
 impl Parse for FieldAttr {
@@ -55,6 +67,15 @@ impl Parse for FieldAttr {
                         return Ok(Self::new(DisplayWith(lit, None, Punctuated::new())))
                     }
                     "format" => return Ok(Self::new(FormatWith(lit, None, Punctuated::new()))),
+                    "align" => return Ok(Self::new(Align(lit))),
+                    "color" => return Ok(Self::new(Color(lit))),
+                    "bound" => return Ok(Self::new(Bound(lit))),
+                    "display_option" => return Ok(Self::new(DisplayOption(lit))),
+                    "join" => return Ok(Self::new(Join(lit))),
+                    "group" => return Ok(Self::new(Group(lit))),
+                    "skip_if_all" => return Ok(Self::new(SkipIfAll(lit))),
+                    "display_if" => return Ok(Self::new(DisplayIf(lit))),
+                    "header_key" => return Ok(Self::new(HeaderKey(lit))),
                     _ => {}
                 }
             }
@@ -65,6 +86,8 @@ impl Parse for FieldAttr {
                 match name_str.as_str() {
                     "skip" => return Ok(Self::new(Skip(lit))),
                     "inline" => return Ok(Self::new(Inline(lit, None))),
+                    "datetime" => return Ok(Self::new(DateTime(lit))),
+                    "nested_table" => return Ok(Self::new(NestedTable(lit))),
                     _ => {}
                 }
             }
@@ -72,8 +95,10 @@ impl Parse for FieldAttr {
             if input.peek(LitInt) {
                 let lit = input.parse::<LitInt>()?;
 
-                if let "order" = name_str.as_str() {
-                    return Ok(Self::new(Order(lit)));
+                match name_str.as_str() {
+                    "order" => return Ok(Self::new(Order(lit))),
+                    "max_width" => return Ok(Self::new(MaxWidth(lit))),
+                    _ => {}
                 }
             }
 
@@ -137,6 +162,13 @@ impl Parse for FieldAttr {
                     None,
                 )))
             }
+            "datetime" => return Ok(Self::new(DateTime(LitBool::new(true, Span::call_site())))),
+            "nested_table" => {
+                return Ok(Self::new(NestedTable(LitBool::new(
+                    true,
+                    Span::call_site(),
+                ))))
+            }
             _ => {}
         }
 
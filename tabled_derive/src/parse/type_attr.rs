@@ -28,6 +28,11 @@ pub enum TypeAttrKind {
     Inline(LitBool, Option<LitStr>),
     RenameAll(LitStr),
     Crate(LitStr),
+    Bound(LitStr),
+    Style(LitStr),
+    Header(LitStr),
+    FooterFn(LitStr),
+    EnumMode(LitStr),
 }
 
 impl Parse for TypeAttr {
@@ -51,8 +56,14 @@ impl Parse for TypeAttr {
             if input.peek(LitStr) {
                 let lit = input.parse::<LitStr>()?;
 
-                if let "rename_all" = name_str.as_str() {
-                    return Ok(Self::new(RenameAll(lit)));
+                match name_str.as_str() {
+                    "rename_all" => return Ok(Self::new(RenameAll(lit))),
+                    "bound" => return Ok(Self::new(Bound(lit))),
+                    "style" => return Ok(Self::new(Style(lit))),
+                    "header" => return Ok(Self::new(Header(lit))),
+                    "footer_fn" => return Ok(Self::new(FooterFn(lit))),
+                    "enum_mode" => return Ok(Self::new(EnumMode(lit))),
+                    _ => {}
                 }
             }
 